@@ -0,0 +1,70 @@
+//! 比较题目列表在压缩前后的读取延迟
+//!
+//! cargo bench --bench read
+
+use std::fs;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use serde_json::json;
+
+use leetcode_cache::compression::Compression;
+use leetcode_cache::database::DataBase;
+use leetcode_cache::options::Options;
+
+/// 构造一个和`ProblemsAll`结构相同、大小相近的JSON
+fn problems_all() -> String {
+    let stat_status_pairs = (1..=3000)
+        .map(|id| json!({
+            "stat": {
+                "question_id": id,
+                "question__title": format!("Problem Title Number {}", id),
+                "question__title_slug": format!("problem-title-number-{}", id),
+                "question__hide": false,
+                "total_acs": id * 37,
+                "total_submitted": id * 71,
+                "total_column_articles": id % 500,
+                "frontend_question_id": id.to_string(),
+                "is_new_question": false,
+            },
+            "status": if id % 3 == 0 { json!("ac") } else { json!(null) },
+            "difficulty": { "level": id % 3 + 1 },
+            "paid_only": id % 7 == 0,
+            "is_favor": false,
+            "frequency": 0,
+            "progress": 0,
+        }))
+        .collect::<Vec<_>>();
+    json!({
+        "user_name": "bench",
+        "num_solved": 1000,
+        "num_total": 3000,
+        "ac_easy": 400,
+        "ac_medium": 500,
+        "ac_hard": 100,
+        "stat_status_pairs": stat_status_pairs,
+    }).to_string()
+}
+
+fn bench_read(c: &mut Criterion) {
+    let value = problems_all();
+    let mut group = c.benchmark_group("read_problems_all");
+    for (name, compression) in [("none", Compression::None), ("lz4", Compression::Lz4)] {
+        let path = format!("benchdb_{}", name);
+        let options = Options {
+            compression,
+            ..Options::default()
+        };
+        let mut db = DataBase::open_with(path.as_str(), options).expect("open failed");
+        db.tree("problems").set("ProblemsAll".to_string(), value.clone()).expect("set failed");
+        println!("{}: {} bytes on disk", name, db.stats().expect("stats failed").disk_size);
+        group.bench_function(name, |b| {
+            b.iter(|| db.tree("problems").get("ProblemsAll").expect("get failed"))
+        });
+        drop(db);
+        fs::remove_dir_all(path).expect("remove dir failed");
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_read);
+criterion_main!(benches);
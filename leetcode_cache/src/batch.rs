@@ -0,0 +1,41 @@
+use crate::database::DEFAULT_TREE;
+use crate::log_file::Command;
+
+/// 一组需要原子写入的命令，通过`DataBase::write`提交
+#[derive(Default)]
+pub struct WriteBatch {
+    pub(crate) cmds: Vec<Command>,
+}
+
+impl WriteBatch {
+    pub fn new() -> Self {
+        WriteBatch::default()
+    }
+
+    pub fn set(&mut self, key: String, value: String) -> &mut Self {
+        self.set_in(DEFAULT_TREE, key, value)
+    }
+
+    /// 删除不存在的key不会报错
+    pub fn remove(&mut self, key: String) -> &mut Self {
+        self.remove_in(DEFAULT_TREE, key)
+    }
+
+    pub fn set_in(&mut self, tree: &str, key: String, value: String) -> &mut Self {
+        self.cmds.push(Command::set(tree.to_string(), key, value));
+        self
+    }
+
+    pub fn remove_in(&mut self, tree: &str, key: String) -> &mut Self {
+        self.cmds.push(Command::remove(tree.to_string(), key));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.cmds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cmds.is_empty()
+    }
+}
@@ -16,7 +16,7 @@ pub(crate) struct BufWriterWithPos<T: Write + Seek> {
 
 impl<T: Read + Seek> BufReaderWithPos<T> {
     pub(crate) fn new(mut file: T) -> Result<Self> {
-        let pos = file.seek(SeekFrom::Current(0))?;
+        let pos = file.stream_position()?;
         Ok(BufReaderWithPos {
             reader: BufReader::new(file),
             pos,
@@ -43,7 +43,7 @@ impl<T: Read + Seek> Seek for BufReaderWithPos<T> {
 
 impl<T: Write + Seek> BufWriterWithPos<T> {
     pub(crate) fn new(mut file: T) -> Result<Self> {
-        let pos = file.seek(SeekFrom::Current(0))?;
+        let pos = file.stream_position()?;
         Ok(BufWriterWithPos {
             writer: BufWriter::new(file),
             pos,
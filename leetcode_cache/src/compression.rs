@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use crate::database::Result;
+
+/// value的压缩方式，记录在每条`Set`命令中
+///
+/// 压缩后的数据以base64的形式保存，以保持log文件是JSON格式
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    #[default]
+    None,
+    Lz4,
+}
+
+impl Compression {
+    pub(crate) fn is_none(&self) -> bool {
+        *self == Compression::None
+    }
+
+    pub(crate) fn encode(self, value: String) -> String {
+        match self {
+            Compression::None => value,
+            Compression::Lz4 => {
+                base64::encode(lz4_flex::compress_prepend_size(value.as_bytes()))
+            }
+        }
+    }
+
+    pub(crate) fn decode(self, value: String) -> Result<String> {
+        match self {
+            Compression::None => Ok(value),
+            Compression::Lz4 => {
+                let compressed = base64::decode(value)?;
+                let bytes = lz4_flex::decompress_size_prepended(&compressed)
+                    .map_err(|e| anyhow::Error::msg(e.to_string()))?;
+                Ok(String::from_utf8(bytes)?)
+            }
+        }
+    }
+}
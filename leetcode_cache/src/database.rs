@@ -1,23 +1,50 @@
 use std::path::PathBuf;
 use std::collections::BTreeMap;
 use std::fs;
+use std::ops::{Bound, RangeBounds};
 
 pub type Result<T> = anyhow::Result<T>;
 
-use crate::log_file::{Command, CommandPos, LogFile};
+use crate::batch::WriteBatch;
+use crate::iter::Iter;
+use crate::log_file::{apply_to_index, Command, CommandPos, Index, LogFile};
+use crate::options::{Options, SyncMode};
+use crate::tree::Tree;
 
-const COMPACTION_THRESHOLD: u64 = 1024 * 1024;
+/// 默认命名空间，`DataBase::set`/`get`等方法直接操作该命名空间
+pub const DEFAULT_TREE: &str = "";
 
 pub struct DataBase {
     path: PathBuf,
     current_id: u64,
     log_files: BTreeMap<u64, LogFile>,
-    index: BTreeMap<String, CommandPos>,
+    index: Index,
     stale_data: u64,
+    options: Options,
+}
+
+/// 数据库的统计信息，大小均为字节数
+#[derive(Debug)]
+pub struct Stats {
+    pub log_files: usize,
+    pub disk_size: u64,
+    pub live_data: u64,
+    pub stale_data: u64,
+    pub trees: BTreeMap<String, TreeStats>,
+}
+
+#[derive(Debug)]
+pub struct TreeStats {
+    pub keys: usize,
+    pub size: u64,
 }
 
 impl DataBase {
     pub fn open<T: Into<PathBuf>>(path: T) -> Result<Self> {
+        DataBase::open_with(path, Options::default())
+    }
+
+    pub fn open_with<T: Into<PathBuf>>(path: T, options: Options) -> Result<Self> {
         let path = path.into();
         fs::create_dir_all(&path)?;
         let mut log_files = BTreeMap::new();
@@ -25,7 +52,7 @@ impl DataBase {
         let mut stale_data = 0;
         LogFile::load_all(path.as_path(), &mut log_files)?;
         let current_id = log_files.keys().last().unwrap_or(&0) + 1;
-        for (_, log_file) in &mut log_files {
+        for log_file in log_files.values_mut() {
             stale_data += log_file.init_index(&mut index)?;
         }
         log_files.insert(current_id, LogFile::create(current_id, &path)?);
@@ -36,46 +63,119 @@ impl DataBase {
             log_files,
             index,
             stale_data,
+            options,
         })
     }
 
+    /// 打开一个命名空间，不存在时在第一次写入后创建
+    pub fn tree(&mut self, name: &str) -> Tree<'_> {
+        Tree::new(self, name)
+    }
+
+    /// 所有非空命名空间的名字，不包含默认命名空间
+    pub fn tree_names(&self) -> Vec<String> {
+        self.index.keys()
+            .filter(|name| name.as_str() != DEFAULT_TREE)
+            .cloned()
+            .collect()
+    }
+
     pub fn set(&mut self, key: String, value: String) -> Result<()> {
-        let cmd = Command::set(key.clone(), value);
+        self.set_in(DEFAULT_TREE, key, value)
+    }
+
+    pub fn get(&mut self, key: &str) -> Result<Option<String>> {
+        self.get_in(DEFAULT_TREE, key)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Result<()> {
+        self.remove_in(DEFAULT_TREE, key)
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.contains_in(DEFAULT_TREE, key)
+    }
+
+    /// 原子地写入一组命令，只flush一次
+    ///
+    /// 写入过程中崩溃时，重新打开后这组命令要么全部生效，要么全部丢弃
+    pub fn write(&mut self, batch: WriteBatch) -> Result<()> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+        let cmds = batch.cmds.into_iter()
+            .map(|cmd| cmd.compress(self.options.compression, self.options.compression_min_size))
+            .collect::<Vec<_>>();
+        let writer = self.log_files.iter_mut().next_back().unwrap().1;
+        let pos = writer.write_pos();
+        let ranges = writer.store_batch(&cmds)?;
+        writer.sync(self.options.sync)?;
+        // Batch头本身不是有效数据
+        self.stale_data += ranges[0].start - pos;
+        for (cmd, range) in cmds.into_iter().zip(ranges) {
+            self.stale_data += apply_to_index(&mut self.index, cmd, CommandPos::from((self.current_id, range)));
+        }
+        self.maybe_compact()?;
+        Ok(())
+    }
+
+    /// 遍历默认命名空间中的所有数据
+    pub fn iter(&mut self) -> Iter<'_> {
+        self.range_in(DEFAULT_TREE, (Bound::Unbounded, Bound::Unbounded), None)
+    }
+
+    /// 遍历默认命名空间中以`prefix`开头的数据
+    pub fn scan_prefix(&mut self, prefix: &str) -> Iter<'_> {
+        self.scan_prefix_in(DEFAULT_TREE, prefix)
+    }
+
+    /// 遍历默认命名空间中key在`range`内的数据
+    ///
+    /// # Panics
+    /// 与`BTreeMap::range`相同，`range`的起点大于终点时panic
+    pub fn range<'k, R: RangeBounds<&'k str>>(&mut self, range: R) -> Iter<'_> {
+        self.range_in(DEFAULT_TREE, (range.start_bound().cloned(), range.end_bound().cloned()), None)
+    }
+
+    pub(crate) fn set_in(&mut self, tree: &str, key: String, value: String) -> Result<()> {
+        let cmd = Command::set(tree.to_string(), key.clone(), value)
+            .compress(self.options.compression, self.options.compression_min_size);
         let writer = self.log_files.iter_mut().next_back().unwrap().1;
         let pos = writer.write_pos();
         writer.store(cmd)?;
-        writer.flush_write()?;
+        writer.sync(self.options.sync)?;
         let new_pos = writer.write_pos();
         // 更新索引
-        if let Some(old_cmd) = self.index.insert(key, CommandPos::from((self.current_id, pos..new_pos))) {
+        let entries = self.index.entry(tree.to_string()).or_default();
+        if let Some(old_cmd) = entries.insert(key, CommandPos::from((self.current_id, pos..new_pos))) {
             self.stale_data += old_cmd.len;
         }
-        if self.stale_data > COMPACTION_THRESHOLD {
-            self.compact()?;
-        }
+        self.maybe_compact()?;
         Ok(())
     }
 
-    pub fn get(&mut self, key: &str) -> Result<Option<String>> {
-        if let Some(cmd_pos) = self.index.get(key) {
+    pub(crate) fn get_in(&mut self, tree: &str, key: &str) -> Result<Option<String>> {
+        if let Some(cmd_pos) = self.index.get(tree).and_then(|entries| entries.get(key)) {
             let reader = self.log_files.get_mut(&cmd_pos.id)
                 .expect("Cannot find reader");
-            if let Command::Set { value, .. } = reader.read_cmd(cmd_pos)? {
-                return Ok(Some(value));
-            } else {
-                return Err(anyhow::Error::msg("Unexpected command"));
-            }
+            return Ok(Some(reader.read_cmd(cmd_pos)?.into_value()?));
         }
         Ok(None)
     }
 
-    pub fn remove(&mut self, key: &str) -> Result<()> {
-        if self.index.contains_key(key) {
-            let cmd = Command::remove(key.to_string());
+    pub(crate) fn remove_in(&mut self, tree: &str, key: &str) -> Result<()> {
+        if self.contains_in(tree, key) {
+            let cmd = Command::remove(tree.to_string(), key.to_string());
             let writer = self.log_files.iter_mut().next_back().unwrap().1;
+            let pos = writer.write_pos();
             writer.store(cmd)?;
-            writer.flush_write()?;
-            let old_cmd = self.index.remove(key).expect("Key not found");
+            writer.sync(self.options.sync)?;
+            self.stale_data += writer.write_pos() - pos;
+            let entries = self.index.get_mut(tree).expect("Tree not found");
+            let old_cmd = entries.remove(key).expect("Key not found");
+            if entries.is_empty() {
+                self.index.remove(tree);
+            }
             self.stale_data += old_cmd.len;
             Ok(())
         } else {
@@ -83,24 +183,101 @@ impl DataBase {
         }
     }
 
-    pub fn contains(&self, key: &str) -> bool {
-        self.index.contains_key(key)
+    pub(crate) fn contains_in(&self, tree: &str, key: &str) -> bool {
+        self.index.get(tree)
+            .map(|entries| entries.contains_key(key))
+            .unwrap_or(false)
+    }
+
+    /// 删除命名空间中的所有数据，只写入一条`Clear`命令
+    pub(crate) fn clear_in(&mut self, tree: &str) -> Result<()> {
+        if let Some(entries) = self.index.remove(tree) {
+            let cmd = Command::clear(tree.to_string());
+            let writer = self.log_files.iter_mut().next_back().unwrap().1;
+            let pos = writer.write_pos();
+            writer.store(cmd)?;
+            writer.sync(self.options.sync)?;
+            self.stale_data += writer.write_pos() - pos;
+            self.stale_data += entries.values().map(|cmd_pos| cmd_pos.len).sum::<u64>();
+        }
+        Ok(())
+    }
+
+    pub(crate) fn scan_prefix_in(&mut self, tree: &str, prefix: &str) -> Iter<'_> {
+        self.range_in(tree, (Bound::Included(prefix), Bound::Unbounded), Some(prefix.to_string()))
+    }
+
+    pub(crate) fn range_in(&mut self,
+                           tree: &str,
+                           range: (Bound<&str>, Bound<&str>),
+                           prefix: Option<String>) -> Iter<'_> {
+        let entries = self.index.get(tree)
+            .map(|entries| entries.range::<str, _>(range));
+        Iter::new(entries, &mut self.log_files, prefix)
+    }
+
+    pub(crate) fn len_in(&self, tree: &str) -> usize {
+        self.index.get(tree).map(BTreeMap::len).unwrap_or(0)
+    }
+
+    /// 命名空间中有效数据在log中占用的字节数
+    pub(crate) fn size_in(&self, tree: &str) -> u64 {
+        self.index.get(tree)
+            .map(|entries| entries.values().map(|cmd_pos| cmd_pos.len).sum())
+            .unwrap_or(0)
+    }
+
+    pub fn stats(&self) -> Result<Stats> {
+        let mut disk_size = 0;
+        for &id in self.log_files.keys() {
+            disk_size += fs::metadata(LogFile::log_file_name(&self.path, id))?.len();
+        }
+        let trees = self.index.keys()
+            .map(|tree| (tree.clone(), TreeStats {
+                keys: self.len_in(tree),
+                size: self.size_in(tree),
+            }))
+            .collect::<BTreeMap<_, _>>();
+        Ok(Stats {
+            log_files: self.log_files.len(),
+            disk_size,
+            live_data: trees.values().map(|tree| tree.size).sum(),
+            stale_data: self.stale_data,
+            trees,
+        })
+    }
+
+    fn maybe_compact(&mut self) -> Result<()> {
+        if !self.options.auto_compact || self.stale_data <= self.options.compaction_threshold {
+            return Ok(());
+        }
+        if let Some(ratio) = self.options.compaction_ratio {
+            let live_data = self.index.keys().map(|tree| self.size_in(tree)).sum::<u64>();
+            if (self.stale_data as f64) < ratio * live_data as f64 {
+                return Ok(());
+            }
+        }
+        self.compact()
     }
 
-    fn compact(&mut self) -> Result<()> {
+    /// 把所有有效数据写入新的log文件，删除旧的log文件
+    pub fn compact(&mut self) -> Result<()> {
         let compaction_id = self.current_id + 1;
         let mut compaction_log_file = LogFile::create(compaction_id, &self.path)?;
         self.current_id += 2;
         let log_file = LogFile::create(self.current_id, &self.path)?;
 
         let mut new_pos = 0;
-        for cmd_pos in &mut self.index.values_mut() {
+        for cmd_pos in self.index.values_mut().flat_map(BTreeMap::values_mut) {
             let reader = self.log_files.get_mut(&cmd_pos.id).expect("reader not found");
-            let cmd = reader.read_cmd(&cmd_pos)?;
+            let cmd = reader.read_cmd(cmd_pos)?;
             compaction_log_file.store(cmd)?;
             *cmd_pos = CommandPos::from((compaction_id, new_pos..new_pos + cmd_pos.len));
             new_pos += cmd_pos.len;
         }
+        // 删除旧的log文件之前，压缩后的数据至少要写入文件
+        let sync = if self.options.sync == SyncMode::Fsync { SyncMode::Fsync } else { SyncMode::Flush };
+        compaction_log_file.sync(sync)?;
 
         for &id in self.log_files.keys() {
             LogFile::remove(id, &self.path)?;
@@ -118,7 +295,10 @@ impl DataBase {
 #[cfg(test)]
 mod tests {
     use std::fs;
+    use crate::batch::WriteBatch;
+    use crate::compression::Compression;
     use crate::database::DataBase;
+    use crate::options::{Options, SyncMode};
     use serial_test::serial;
 
     #[test]
@@ -133,17 +313,191 @@ mod tests {
     fn test_base() {
         let mut db = DataBase::open("testdb").expect("open failed");
         db.set("Hello".to_string(), "World".to_string()).expect("set failed");
-        assert_eq!(db.contains("Hello"), true);
+        assert!(db.contains("Hello"));
         assert_eq!(db.get("Hello").expect("get failed").unwrap(), "World".to_string());
         db.set("A".to_string(), "World".to_string()).expect("set failed");
-        assert_eq!(db.contains("A"), true);
+        assert!(db.contains("A"));
         assert_eq!(db.get("A").expect("get failed").unwrap(), "World".to_string());
-        assert_eq!(db.contains("Hello"), true);
+        assert!(db.contains("Hello"));
         assert_eq!(db.get("Hello").expect("get failed").unwrap(), "World".to_string());
         db.set("Hello".to_string(), "NewValue".to_string()).expect("set failed");
         assert_eq!(db.get("Hello").expect("get failed").unwrap(), "NewValue".to_string());
         db.remove("A").expect("remove failed");
-        assert_eq!(db.contains("A"), false);
+        assert!(!db.contains("A"));
+        fs::remove_dir_all("testdb").expect("remove dir failed");
+    }
+
+    #[test]
+    #[serial]
+    fn test_tree() {
+        let mut db = DataBase::open("testdb").expect("open failed");
+        db.set("Hello".to_string(), "World".to_string()).expect("set failed");
+        db.tree("questions").set("Hello".to_string(), "Question".to_string()).expect("set failed");
+        db.tree("questions").set("A".to_string(), "B".to_string()).expect("set failed");
+        db.tree("auth").set("Cookie".to_string(), "Token".to_string()).expect("set failed");
+        assert_eq!(db.get("Hello").expect("get failed").unwrap(), "World".to_string());
+        assert_eq!(db.tree("questions").get("Hello").expect("get failed").unwrap(), "Question".to_string());
+        assert!(!db.contains("A"));
+        assert_eq!(db.tree("questions").len(), 2);
+        assert_eq!(db.tree_names(), vec!["auth".to_string(), "questions".to_string()]);
+
+        db.tree("questions").clear().expect("clear failed");
+        assert!(db.tree("questions").is_empty());
+        assert_eq!(db.tree("questions").size(), 0);
+        assert!(db.tree("auth").contains("Cookie"));
+        assert!(db.contains("Hello"));
+        drop(db);
+
+        let mut db = DataBase::open("testdb").expect("reopen failed");
+        assert!(db.tree("questions").is_empty());
+        assert_eq!(db.tree("auth").get("Cookie").expect("get failed").unwrap(), "Token".to_string());
+        assert_eq!(db.get("Hello").expect("get failed").unwrap(), "World".to_string());
+        fs::remove_dir_all("testdb").expect("remove dir failed");
+    }
+
+    fn collect_keys(iter: crate::iter::Iter<'_>) -> Vec<String> {
+        iter.map(|item| item.expect("read failed").0).collect()
+    }
+
+    #[test]
+    #[serial]
+    fn test_iter() {
+        let mut db = DataBase::open("testdb").expect("open failed");
+        db.set("note:1".to_string(), "a".to_string()).expect("set failed");
+        db.set("note:2".to_string(), "b".to_string()).expect("set failed");
+        db.tree("questions").set("note:3".to_string(), "c".to_string()).expect("set failed");
+        drop(db);
+
+        // 新的数据写入另一个log文件
+        let mut db = DataBase::open("testdb").expect("reopen failed");
+        db.set("note:0".to_string(), "z".to_string()).expect("set failed");
+        db.set("other".to_string(), "o".to_string()).expect("set failed");
+        db.set("note:2".to_string(), "bb".to_string()).expect("set failed");
+
+        let items = db.scan_prefix("note:").collect::<Result<Vec<_>, _>>().expect("read failed");
+        assert_eq!(items, vec![
+            ("note:0".to_string(), "z".to_string()),
+            ("note:1".to_string(), "a".to_string()),
+            ("note:2".to_string(), "bb".to_string()),
+        ]);
+        assert_eq!(collect_keys(db.iter()), vec!["note:0", "note:1", "note:2", "other"]);
+        assert_eq!(collect_keys(db.range("note:1".."other")), vec!["note:1", "note:2"]);
+        assert_eq!(collect_keys(db.range("note:1"..)), vec!["note:1", "note:2", "other"]);
+        assert_eq!(collect_keys(db.tree("questions").iter()), vec!["note:3"]);
+        assert!(db.tree("empty").scan_prefix("note:").next().is_none());
+
+        // 写入足够多的旧数据，触发压缩
+        let big_value = "x".repeat(64 * 1024);
+        for _ in 0..20 {
+            db.set("big".to_string(), big_value.clone()).expect("set failed");
+        }
+        assert_eq!(collect_keys(db.iter()), vec!["big", "note:0", "note:1", "note:2", "other"]);
+        assert_eq!(db.get("note:2").expect("get failed").unwrap(), "bb".to_string());
+        fs::remove_dir_all("testdb").expect("remove dir failed");
+    }
+
+    #[test]
+    #[serial]
+    fn test_write_batch() {
+        let mut db = DataBase::open("testdb").expect("open failed");
+        db.set("A".to_string(), "1".to_string()).expect("set failed");
+        let mut batch = WriteBatch::new();
+        batch.set("B".to_string(), "2".to_string())
+            .remove("A".to_string())
+            .set_in("questions", "C".to_string(), "3".to_string());
+        db.write(batch).expect("write failed");
+        assert!(!db.contains("A"));
+        assert_eq!(db.get("B").expect("get failed").unwrap(), "2".to_string());
+        assert_eq!(db.tree("questions").get("C").expect("get failed").unwrap(), "3".to_string());
+        drop(db);
+
+        let mut db = DataBase::open("testdb").expect("reopen failed");
+        assert!(!db.contains("A"));
+        assert_eq!(db.get("B").expect("get failed").unwrap(), "2".to_string());
+        let mut batch = WriteBatch::new();
+        batch.set("D".to_string(), "4".to_string())
+            .set("E".to_string(), "5".to_string());
+        db.write(batch).expect("write failed");
+        drop(db);
+
+        // 模拟写入一半时崩溃：截断最后一个log文件
+        let last_log = fs::read_dir("testdb").expect("read dir failed")
+            .map(|entry| entry.expect("read entry failed").path())
+            .max_by_key(|path| {
+                path.file_stem().unwrap().to_str().unwrap().parse::<u64>().unwrap()
+            })
+            .unwrap();
+        let file = fs::OpenOptions::new().write(true).open(&last_log).expect("open log failed");
+        let len = file.metadata().expect("metadata failed").len();
+        file.set_len(len - 5).expect("truncate failed");
+        drop(file);
+
+        let mut db = DataBase::open("testdb").expect("reopen failed");
+        assert!(!db.contains("D"));
+        assert!(!db.contains("E"));
+        assert_eq!(db.get("B").expect("get failed").unwrap(), "2".to_string());
+        fs::remove_dir_all("testdb").expect("remove dir failed");
+    }
+
+    #[test]
+    #[serial]
+    fn test_options_and_stats() {
+        let options = Options {
+            sync: SyncMode::None,
+            auto_compact: false,
+            ..Options::default()
+        };
+        let mut db = DataBase::open_with("testdb", options).expect("open failed");
+        db.set("A".to_string(), "1".to_string()).expect("set failed");
+        // 数据还在缓冲区中也能读到
+        assert_eq!(db.get("A").expect("get failed").unwrap(), "1".to_string());
+        db.tree("questions").set("B".to_string(), "2".to_string()).expect("set failed");
+        let big_value = "x".repeat(64 * 1024);
+        for _ in 0..20 {
+            db.set("big".to_string(), big_value.clone()).expect("set failed");
+        }
+
+        let stats = db.stats().expect("stats failed");
+        assert!(stats.stale_data > 1024 * 1024);
+        assert_eq!(stats.trees[""].keys, 2);
+        assert_eq!(stats.trees["questions"].keys, 1);
+        assert_eq!(stats.live_data, stats.trees.values().map(|tree| tree.size).sum::<u64>());
+
+        db.compact().expect("compact failed");
+        let stats = db.stats().expect("stats failed");
+        assert_eq!(stats.stale_data, 0);
+        assert_eq!(stats.log_files, 2);
+        assert_eq!(stats.disk_size, stats.live_data);
+        assert_eq!(db.get("big").expect("get failed").unwrap(), big_value);
+        assert_eq!(db.tree("questions").get("B").expect("get failed").unwrap(), "2".to_string());
+        fs::remove_dir_all("testdb").expect("remove dir failed");
+    }
+
+    #[test]
+    #[serial]
+    fn test_compression() {
+        let options = Options {
+            compression: Compression::Lz4,
+            compression_min_size: 16,
+            ..Options::default()
+        };
+        let mut db = DataBase::open_with("testdb", options).expect("open failed");
+        let big_value = "leetcode ".repeat(1024);
+        db.set("small".to_string(), "value".to_string()).expect("set failed");
+        db.set("big".to_string(), big_value.clone()).expect("set failed");
+        let mut batch = WriteBatch::new();
+        batch.set_in("questions", "batch".to_string(), big_value.clone());
+        db.write(batch).expect("write failed");
+        assert_eq!(db.get("big").expect("get failed").unwrap(), big_value);
+        assert!(db.stats().expect("stats failed").disk_size < big_value.len() as u64);
+        drop(db);
+
+        // 读取时按记录中的压缩方式解压，与打开时的选项无关
+        let mut db = DataBase::open("testdb").expect("reopen failed");
+        assert_eq!(db.get("small").expect("get failed").unwrap(), "value".to_string());
+        assert_eq!(db.get("big").expect("get failed").unwrap(), big_value);
+        let items = db.tree("questions").iter().collect::<Result<Vec<_>, _>>().expect("read failed");
+        assert_eq!(items, vec![("batch".to_string(), big_value.clone())]);
         fs::remove_dir_all("testdb").expect("remove dir failed");
     }
 }
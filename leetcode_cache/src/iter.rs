@@ -0,0 +1,47 @@
+use std::collections::btree_map::Range;
+use std::collections::BTreeMap;
+
+use crate::database::Result;
+use crate::log_file::{Command, CommandPos, LogFile};
+
+/// 按key的顺序遍历`(key, value)`，value在调用`next`时才从log中读取
+///
+/// 迭代器持有数据库的可变借用，遍历期间不会发生写入和压缩
+pub struct Iter<'a> {
+    entries: Option<Range<'a, String, CommandPos>>,
+    log_files: &'a mut BTreeMap<u64, LogFile>,
+    prefix: Option<String>,
+}
+
+impl<'a> Iter<'a> {
+    pub(crate) fn new(entries: Option<Range<'a, String, CommandPos>>,
+                      log_files: &'a mut BTreeMap<u64, LogFile>,
+                      prefix: Option<String>) -> Self {
+        Iter {
+            entries,
+            log_files,
+            prefix,
+        }
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = Result<(String, String)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, cmd_pos) = self.entries.as_mut()?.next()?;
+        if let Some(prefix) = &self.prefix {
+            if !key.starts_with(prefix.as_str()) {
+                // key有序，之后不会再有匹配的key
+                self.entries = None;
+                return None;
+            }
+        }
+        let reader = self.log_files.get_mut(&cmd_pos.id)
+            .expect("Cannot find reader");
+        let item = reader.read_cmd(cmd_pos)
+            .and_then(Command::into_value)
+            .map(|value| (key.clone(), value));
+        Some(item)
+    }
+}
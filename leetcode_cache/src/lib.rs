@@ -1,4 +1,9 @@
+pub mod batch;
+pub mod compression;
 pub mod database;
+pub mod iter;
+pub mod options;
+pub mod tree;
 
 mod buf_util;
 mod log_file;
@@ -11,22 +11,64 @@ use anyhow::Result;
 use serde_json::Deserializer;
 
 use crate::buf_util::{BufReaderWithPos, BufWriterWithPos};
+use crate::compression::Compression;
+use crate::options::SyncMode;
 
 
 /// log文件中存储的命令
+///
+/// `tree` 为空字符串时表示默认命名空间，兼容旧版本写入的log
 #[derive(Serialize, Deserialize, Debug)]
 pub(crate) enum Command {
-    Set { key: String, value: String },
-    Remove { key: String },
+    Set {
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        tree: String,
+        key: String,
+        value: String,
+        #[serde(default, skip_serializing_if = "Compression::is_none")]
+        compression: Compression,
+    },
+    Remove {
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        tree: String,
+        key: String,
+    },
+    Clear { tree: String },
+    /// 之后的`count`条命令属于同一次批量写入
+    Batch { count: usize },
 }
 
 impl Command {
-    pub(crate) fn set(key: String, value: String) -> Self {
-        Command::Set { key, value }
+    pub(crate) fn set(tree: String, key: String, value: String) -> Self {
+        Command::Set { tree, key, value, compression: Compression::None }
     }
 
-    pub(crate) fn remove(key: String) -> Self {
-        Command::Remove { key }
+    /// 压缩长度不小于`min_size`的value，其它命令保持不变
+    pub(crate) fn compress(self, compression: Compression, min_size: usize) -> Self {
+        match self {
+            Command::Set { tree, key, value, compression: Compression::None }
+            if !compression.is_none() && value.len() >= min_size => {
+                let value = compression.encode(value);
+                Command::Set { tree, key, value, compression }
+            }
+            cmd => cmd,
+        }
+    }
+
+    /// 取出`Set`命令中解压后的value
+    pub(crate) fn into_value(self) -> Result<String> {
+        match self {
+            Command::Set { value, compression, .. } => compression.decode(value),
+            _ => Err(anyhow::Error::msg("Unexpected command")),
+        }
+    }
+
+    pub(crate) fn remove(tree: String, key: String) -> Self {
+        Command::Remove { tree, key }
+    }
+
+    pub(crate) fn clear(tree: String) -> Self {
+        Command::Clear { tree }
     }
 }
 
@@ -48,6 +90,43 @@ impl From<(u64, Range<u64>)> for CommandPos {
     }
 }
 
+/// 命名空间 -> (key -> CommandPos)
+pub(crate) type Index = BTreeMap<String, BTreeMap<String, CommandPos>>;
+
+/// 把一条已写入log的命令应用到索引上
+///
+/// # Return
+/// 因此变为旧数据的大小
+pub(crate) fn apply_to_index(index: &mut Index, cmd: Command, cmd_pos: CommandPos) -> u64 {
+    match cmd {
+        Command::Set { tree, key, .. } => {
+            index.entry(tree).or_default()
+                .insert(key, cmd_pos)
+                .map(|old_cmd| old_cmd.len)
+                .unwrap_or(0)
+        }
+        Command::Remove { tree, key } => {
+            let mut stale_data = cmd_pos.len;
+            if let Some(entries) = index.get_mut(&tree) {
+                if let Some(old_cmd) = entries.remove(&key) {
+                    stale_data += old_cmd.len;
+                }
+                if entries.is_empty() {
+                    index.remove(&tree);
+                }
+            }
+            stale_data
+        }
+        Command::Clear { tree } => {
+            let stale_data = index.remove(&tree)
+                .map(|entries| entries.values().map(|cmd_pos| cmd_pos.len).sum())
+                .unwrap_or(0);
+            stale_data + cmd_pos.len
+        }
+        Command::Batch { .. } => cmd_pos.len,
+    }
+}
+
 
 pub(crate) struct LogFile {
     id: u64,
@@ -56,7 +135,7 @@ pub(crate) struct LogFile {
 }
 
 impl LogFile {
-    fn log_file_name<T: AsRef<Path>>(path: T, id: u64) -> PathBuf {
+    pub(crate) fn log_file_name<T: AsRef<Path>>(path: T, id: u64) -> PathBuf {
         path.as_ref().join(format!("{}.log", id))
     }
 
@@ -77,7 +156,7 @@ impl LogFile {
     }
 
     pub(crate) fn load_all(path: &Path, log_files: &mut BTreeMap<u64, LogFile>) -> Result<()> {
-        let mut ids: Vec<u64> = fs::read_dir(&path)?
+        let mut ids: Vec<u64> = fs::read_dir(path)?
             .flat_map(|dir_entry| -> Result<_>{ Ok(dir_entry?.path()) })
             .filter(|path| path.is_file() && path.extension() == Some("log".as_ref()))
             .filter_map(|path| {
@@ -101,45 +180,98 @@ impl LogFile {
 
     /// # Return
     /// 旧数据的大小
-    pub(crate) fn init_index(&mut self, index: &mut BTreeMap<String, CommandPos>) -> Result<u64> {
+    pub(crate) fn init_index(&mut self, index: &mut Index) -> Result<u64> {
         let mut pos = self.reader.as_mut().unwrap().seek(SeekFrom::Start(0))?;
         let mut stream = Deserializer::from_reader(self.reader.as_mut().unwrap()).into_iter::<Command>();
         let mut stale_data: u64 = 0; // 旧数据
+        // 正在读取的批量写入还剩下的命令数量，以及已读取的命令
+        let mut batch_remaining: usize = 0;
+        let mut batch_cmds = Vec::new();
         while let Some(cmd) = stream.next() {
             let new_pos = stream.byte_offset() as u64;
-            match cmd? {
-                Command::Set { key, .. } => {
-                    if let Some(old_cmd) = index.insert(key, CommandPos::from((self.id, pos..new_pos))) {
-                        stale_data += old_cmd.len;
+            let cmd = match cmd {
+                Ok(cmd) => cmd,
+                // 写入时崩溃会留下不完整的末尾，忽略它
+                Err(e) if e.is_eof() => break,
+                Err(e) => return Err(e.into()),
+            };
+            match cmd {
+                Command::Batch { count } => {
+                    if batch_remaining > 0 {
+                        return Err(anyhow::Error::msg("Unexpected nested batch"));
                     }
+                    batch_remaining = count;
+                    stale_data += new_pos - pos;
                 }
-                Command::Remove { key } => {
-                    if let Some(old_cmd) = index.remove(&key) {
-                        stale_data += old_cmd.len;
+                cmd if batch_remaining > 0 => {
+                    batch_cmds.push((cmd, pos..new_pos));
+                    batch_remaining -= 1;
+                    if batch_remaining == 0 {
+                        for (cmd, range) in batch_cmds.drain(..) {
+                            stale_data += apply_to_index(index, cmd, CommandPos::from((self.id, range)));
+                        }
                     }
-                    stale_data += new_pos - pos;
+                }
+                cmd => {
+                    stale_data += apply_to_index(index, cmd, CommandPos::from((self.id, pos..new_pos)));
                 }
             }
             pos = new_pos;
         }
+        // 不完整的批量写入整体丢弃
+        stale_data += batch_cmds.iter().map(|(_, range)| range.end - range.start).sum::<u64>();
         Ok(stale_data)
     }
 
     pub(crate) fn store(&mut self, cmd: Command) -> Result<()> {
-        serde_json::to_writer(&mut self.writer.as_mut().unwrap(), &cmd)?;
+        serde_json::to_writer(self.writer.as_mut().unwrap(), &cmd)?;
         Ok(())
     }
 
+    /// 把多条命令作为一个整体写入，前面加上`Batch`头
+    ///
+    /// # Return
+    /// 每条命令在log中的位置
+    pub(crate) fn store_batch(&mut self, cmds: &[Command]) -> Result<Vec<Range<u64>>> {
+        let start = self.write_pos();
+        let mut buf = Vec::new();
+        serde_json::to_writer(&mut buf, &Command::Batch { count: cmds.len() })?;
+        let mut ranges = Vec::with_capacity(cmds.len());
+        for cmd in cmds {
+            let pos = buf.len() as u64;
+            serde_json::to_writer(&mut buf, cmd)?;
+            ranges.push(start + pos..start + buf.len() as u64);
+        }
+        self.writer.as_mut().unwrap().write_all(&buf)?;
+        Ok(ranges)
+    }
+
     pub(crate) fn flush_write(&mut self) -> Result<()> {
         self.writer.as_mut().unwrap().flush()?;
         Ok(())
     }
 
+    pub(crate) fn sync(&mut self, mode: SyncMode) -> Result<()> {
+        match mode {
+            SyncMode::None => {}
+            SyncMode::Flush => self.flush_write()?,
+            SyncMode::Fsync => {
+                self.flush_write()?;
+                self.writer.as_ref().unwrap().writer.get_ref().sync_data()?;
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn write_pos(&self) -> u64 {
         self.writer.as_ref().unwrap().pos
     }
 
     pub(crate) fn read_cmd(&mut self, cmd_pos: &CommandPos) -> Result<Command> {
+        // SyncMode::None时数据可能还在写缓冲区中
+        if let Some(writer) = self.writer.as_mut() {
+            writer.flush()?;
+        }
         let reader = self.reader.as_mut().unwrap();
         reader.seek(SeekFrom::Start(cmd_pos.pos))?;
         let cmd: Command = serde_json::from_reader(reader.take(cmd_pos.len))?;
@@ -162,7 +294,7 @@ mod tests {
 
     impl RAIIFile {
         fn new<T: AsRef<Path>>(path: T) -> RAIIFile {
-            File::create(path.as_ref()).expect(format!("create {} failed", path.as_ref().display()).as_str());
+            File::create(path.as_ref()).unwrap_or_else(|_| panic!("create {} failed", path.as_ref().display()));
             RAIIFile {
                 path: PathBuf::from(path.as_ref()),
             }
@@ -171,7 +303,7 @@ mod tests {
 
     impl Drop for RAIIFile {
         fn drop(&mut self) {
-            fs::remove_file(self.path.as_path()).expect(format!("remove {} failed", self.path.display()).as_str());
+            fs::remove_file(self.path.as_path()).unwrap_or_else(|_| panic!("remove {} failed", self.path.display()));
         }
     }
 
@@ -187,9 +319,9 @@ mod tests {
         let mut log_files = BTreeMap::new();
         LogFile::load_all(".".as_ref(), &mut log_files).expect("load_all failed");
         assert_eq!(log_files.len(), 4);
-        assert_eq!(log_files.contains_key(&1), true);
-        assert_eq!(log_files.contains_key(&2), true);
-        assert_eq!(log_files.contains_key(&4), true);
-        assert_eq!(log_files.contains_key(&12), true);
+        assert!(log_files.contains_key(&1));
+        assert!(log_files.contains_key(&2));
+        assert!(log_files.contains_key(&4));
+        assert!(log_files.contains_key(&12));
     }
 }
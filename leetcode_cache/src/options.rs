@@ -0,0 +1,45 @@
+/// 每次写入后如何持久化
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncMode {
+    /// 只写入缓冲区，由缓冲区满或关闭数据库时写入文件
+    None,
+    /// 写入文件，但不等待落盘
+    Flush,
+    /// 写入文件并fsync
+    Fsync,
+}
+
+use crate::compression::Compression;
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub sync: SyncMode,
+
+    /// 新写入的value使用的压缩方式，已写入的数据按各自记录的方式读取
+    pub compression: Compression,
+
+    /// 小于该字节数的value不压缩
+    pub compression_min_size: usize,
+
+    /// 旧数据超过该字节数时才会压缩
+    pub compaction_threshold: u64,
+
+    /// 旧数据与有效数据的比值也超过该值时才会压缩，`None`表示不考虑比值
+    pub compaction_ratio: Option<f64>,
+
+    /// 是否在写入时自动压缩，关闭后只能调用`DataBase::compact`
+    pub auto_compact: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            sync: SyncMode::Flush,
+            compression: Compression::None,
+            compression_min_size: 4 * 1024,
+            compaction_threshold: 1024 * 1024,
+            compaction_ratio: None,
+            auto_compact: true,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::database::{DataBase, Result};
+use crate::iter::Iter;
+
+/// 数据库中的一个命名空间
+///
+/// 不同命名空间中的key互不影响，可以单独清空和统计
+pub struct Tree<'a> {
+    db: &'a mut DataBase,
+    name: String,
+}
+
+impl<'a> Tree<'a> {
+    pub(crate) fn new(db: &'a mut DataBase, name: &str) -> Self {
+        Tree {
+            db,
+            name: name.to_string(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set(&mut self, key: String, value: String) -> Result<()> {
+        self.db.set_in(&self.name, key, value)
+    }
+
+    pub fn get(&mut self, key: &str) -> Result<Option<String>> {
+        self.db.get_in(&self.name, key)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Result<()> {
+        self.db.remove_in(&self.name, key)
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.db.contains_in(&self.name, key)
+    }
+
+    pub fn iter(&mut self) -> Iter<'_> {
+        self.db.range_in(&self.name, (Bound::Unbounded, Bound::Unbounded), None)
+    }
+
+    pub fn scan_prefix(&mut self, prefix: &str) -> Iter<'_> {
+        self.db.scan_prefix_in(&self.name, prefix)
+    }
+
+    /// # Panics
+    /// `range`的起点大于终点时panic
+    pub fn range<'k, R: RangeBounds<&'k str>>(&mut self, range: R) -> Iter<'_> {
+        self.db.range_in(&self.name, (range.start_bound().cloned(), range.end_bound().cloned()), None)
+    }
+
+    /// 清空命名空间，不影响其它命名空间
+    pub fn clear(&mut self) -> Result<()> {
+        self.db.clear_in(&self.name)
+    }
+
+    /// key的数量
+    pub fn len(&self) -> usize {
+        self.db.len_in(&self.name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 有效数据占用的字节数
+    pub fn size(&self) -> u64 {
+        self.db.size_in(&self.name)
+    }
+}
@@ -1,17 +1,41 @@
 use std::collections::HashMap;
-use crate::leetcode::cli::{Pick, Submit};
+use crate::leetcode::cli::{Daily, Export, Hint, List, Pick, Prefetch, RandomArgs, Show, Solutions, Stat, Submit};
 
 use crate::leetcode::error::Result;
+use crate::leetcode::filter::Filter;
+use crate::leetcode::net::user_status::UserStatus;
 use crate::leetcode::command::auth::{self, AuthPlugin};
+use crate::leetcode::command::cache::CachePlugin;
+use crate::leetcode::command::daily::DailyPlugin;
+use crate::leetcode::command::export::ExportPlugin;
+use crate::leetcode::command::favorites::FavoritesPlugin;
+use crate::leetcode::command::hint::HintPlugin;
 use crate::leetcode::command::list::ListPlugin;
-use crate::leetcode::command::pick::PickPlugin;
+use crate::leetcode::command::note::NotePlugin;
+use crate::leetcode::command::pick::{Conflict, PickPlugin};
+use crate::leetcode::command::plan::PlanPlugin;
+use crate::leetcode::command::review::ReviewPlugin;
+use crate::leetcode::command::show::ShowPlugin;
+use crate::leetcode::command::solutions::SolutionsPlugin;
+use crate::leetcode::command::stat::StatPlugin;
 use crate::leetcode::command::submit::SubmitPlugin;
 
 pub struct Leetcode {
     auth_plugins: HashMap<String, Box<dyn AuthPlugin>>,
     list_plugin: ListPlugin,
     pick_plugin: PickPlugin,
+    show_plugin: ShowPlugin,
+    hint_plugin: HintPlugin,
+    daily_plugin: DailyPlugin,
+    stat_plugin: StatPlugin,
+    favorites_plugin: FavoritesPlugin,
+    plan_plugin: PlanPlugin,
+    note_plugin: NotePlugin,
+    review_plugin: ReviewPlugin,
+    solutions_plugin: SolutionsPlugin,
+    export_plugin: ExportPlugin,
     submit_plugin: SubmitPlugin,
+    cache_plugin: CachePlugin,
 }
 
 impl Leetcode {
@@ -19,12 +43,34 @@ impl Leetcode {
         let auth_plugins = auth::get_plugins();
         let list_plugin = ListPlugin::new();
         let pick_plugin = PickPlugin::new();
+        let show_plugin = ShowPlugin::new();
+        let hint_plugin = HintPlugin::new();
+        let daily_plugin = DailyPlugin::new();
+        let stat_plugin = StatPlugin::new();
+        let favorites_plugin = FavoritesPlugin::new();
+        let plan_plugin = PlanPlugin::new();
+        let note_plugin = NotePlugin::new();
+        let review_plugin = ReviewPlugin::new();
+        let solutions_plugin = SolutionsPlugin::new();
+        let export_plugin = ExportPlugin::new();
         let submit_plugin = SubmitPlugin::new();
+        let cache_plugin = CachePlugin::new();
         Leetcode {
             auth_plugins,
             list_plugin,
             pick_plugin,
+            show_plugin,
+            hint_plugin,
+            daily_plugin,
+            stat_plugin,
+            favorites_plugin,
+            plan_plugin,
+            note_plugin,
+            review_plugin,
+            solutions_plugin,
+            export_plugin,
             submit_plugin,
+            cache_plugin,
         }
     }
 
@@ -40,23 +86,204 @@ impl Leetcode {
         Ok(())
     }
 
-    pub async fn list_problems(&mut self) -> Result<()> {
+    pub async fn list_problems(&mut self, list: List) -> Result<()> {
+        let filter = Filter::new(&list.filter).await?;
         self.list_plugin.fetch_problems_all().await?;
-        self.list_plugin.list_problems_all().await;
-        Ok(())
+        self.list_plugin.list_problems_all(&filter, &list).await
     }
 
     pub async fn pick_problem(&mut self, pick: Pick) -> Result<()> {
         self.pick_plugin.fetch_problems_all().await?;
-        self.pick_plugin.fetch_question_data(pick.question_id).await?;
-        self.pick_plugin.save_to_file(pick.language).await?;
+        let question_id = match pick.question_id {
+            Some(question_id) => question_id,
+            None => {
+                let premium = !pick.random.include_locked && UserStatus::fetch().await?.is_premium();
+                let filter = Filter::new(&pick.random.filter_args(premium)).await?;
+                match self.pick_plugin.random_question_id(&filter, pick.random.weighted) {
+                    Some(question_id) => question_id,
+                    None => {
+                        println!("no problem matches the filters");
+                        return Ok(());
+                    }
+                }
+            }
+        };
+        self.pick_plugin.fetch_question_data(question_id, pick.refresh).await?;
+        let on_conflict = if pick.force {
+            Some(Conflict::Overwrite)
+        } else if pick.fresh {
+            Some(Conflict::Backup)
+        } else {
+            None
+        };
+        self.pick_plugin.save_to_file(pick.language, on_conflict, pick.readme).await?;
+        Ok(())
+    }
+
+    pub async fn show_problem(&mut self, show: Show) -> Result<()> {
+        self.show_plugin.fetch_problems_all().await?;
+        self.show_plugin.fetch_question_data(show.question_id, show.refresh).await?;
+        self.show_plugin.show(show.hints).await
+    }
+
+    pub async fn show_hints(&mut self, hint: Hint) -> Result<()> {
+        self.hint_plugin.fetch_problems_all().await?;
+        self.hint_plugin.fetch_question_data(hint.question_id).await?;
+        if hint.reset {
+            self.hint_plugin.reset().await?;
+        } else {
+            self.hint_plugin.reveal(hint.n).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn daily(&mut self, daily: Daily) -> Result<()> {
+        self.daily_plugin.fetch_daily().await?;
+        self.daily_plugin.show().await?;
+        if daily.pick {
+            self.pick_problem(Pick {
+                question_id: Some(self.daily_plugin.question_id()),
+                language: daily.language,
+                refresh: false,
+                force: daily.force,
+                fresh: false,
+                readme: false,
+                random: RandomArgs::default(),
+            }).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn stat(&mut self, stat: Stat) -> Result<()> {
+        self.stat_plugin.fetch().await?;
+        self.stat_plugin.show(stat.weeks);
+        Ok(())
+    }
+
+    pub async fn star(&mut self, question_id: i32, starred: bool) -> Result<()> {
+        self.favorites_plugin.star(question_id, starred).await
+    }
+
+    pub async fn list_favorites(&mut self) -> Result<()> {
+        self.favorites_plugin.list().await
+    }
+
+    pub async fn create_favorite(&mut self, name: &str, is_public: bool, question_id: i32) -> Result<()> {
+        self.favorites_plugin.create(name, is_public, question_id).await
+    }
+
+    pub async fn add_favorite(&mut self, list: &str, question_id: i32) -> Result<()> {
+        self.favorites_plugin.add(list, question_id).await
+    }
+
+    pub async fn remove_favorite(&mut self, list: &str, question_id: i32) -> Result<()> {
+        self.favorites_plugin.remove(list, question_id).await
+    }
+
+    pub async fn list_plans(&mut self) -> Result<()> {
+        self.plan_plugin.fetch_problems_all().await?;
+        self.plan_plugin.list().await
+    }
+
+    pub async fn show_plan(&mut self, plan: &str, refresh: bool) -> Result<()> {
+        self.plan_plugin.fetch_problems_all().await?;
+        self.plan_plugin.show(plan, refresh).await
+    }
+
+    pub async fn pick_next_in_plan(&mut self, plan: &str, language: Option<String>, force: bool) -> Result<()> {
+        self.plan_plugin.fetch_problems_all().await?;
+        if let Some(question_id) = self.plan_plugin.next_question_id(plan).await? {
+            self.pick_problem(Pick {
+                question_id: Some(question_id),
+                language,
+                refresh: false,
+                force,
+                fresh: false,
+                readme: false,
+                random: RandomArgs::default(),
+            }).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn note(&mut self, question_id: i32, edit: bool) -> Result<()> {
+        self.note_plugin.fetch_problems_all().await?;
+        if edit {
+            self.note_plugin.edit(question_id).await
+        } else {
+            self.note_plugin.show(question_id).await
+        }
+    }
+
+    pub async fn search_notes(&mut self, query: &str) -> Result<()> {
+        self.note_plugin.fetch_problems_all().await?;
+        self.note_plugin.search(query).await
+    }
+
+    pub async fn review_today(&mut self, pick: bool, language: Option<String>) -> Result<()> {
+        self.review_plugin.fetch_problems_all().await?;
+        if let Some(question_id) = self.review_plugin.today().await? {
+            if pick {
+                self.pick_problem(Pick {
+                    question_id: Some(question_id),
+                    language,
+                    refresh: false,
+                    force: false,
+                    fresh: true,
+                    readme: false,
+                    random: RandomArgs::default(),
+                }).await?;
+            }
+        }
         Ok(())
     }
 
+    pub async fn review_done(&mut self, question_id: i32, grade: u8) -> Result<()> {
+        self.review_plugin.fetch_problems_all().await?;
+        self.review_plugin.done(question_id, grade).await
+    }
+
+    pub async fn solutions(&mut self, solutions: Solutions) -> Result<()> {
+        self.solutions_plugin.fetch_problems_all().await?;
+        match solutions.n {
+            Some(n) => self.solutions_plugin.show(solutions.question_id, n, solutions.lang.as_deref()).await,
+            None => self.solutions_plugin.list(solutions.question_id, solutions.limit).await,
+        }
+    }
+
+    pub async fn export(&mut self, export: Export) -> Result<()> {
+        self.export_plugin.fetch_problems_all().await?;
+        self.export_plugin.export(&export.dir, export.force).await
+    }
+
+    pub async fn browse(&mut self) -> Result<()> {
+        crate::leetcode::browser::run().await
+    }
+
     pub async fn sumbit(&mut self, submit: Submit) -> Result<()> {
         self.submit_plugin.submit_code(submit.filename.as_str(),
                                        submit.test_data).await?;
-        self.submit_plugin.show_judge_result().await?;
+        self.submit_plugin.show_judge_result(submit.grade).await?;
+        Ok(())
+    }
+
+    pub async fn clear_cache(&mut self, tree: String) -> Result<()> {
+        self.cache_plugin.clear(tree.as_str()).await?;
+        Ok(())
+    }
+
+    pub async fn show_cache_stats(&mut self) -> Result<()> {
+        self.cache_plugin.stats().await?;
+        Ok(())
+    }
+
+    pub async fn compact_cache(&mut self) -> Result<()> {
+        self.cache_plugin.compact().await?;
+        Ok(())
+    }
+
+    pub async fn prefetch(&mut self, prefetch: Prefetch) -> Result<()> {
+        self.cache_plugin.prefetch(prefetch).await?;
         Ok(())
     }
 }
@@ -0,0 +1,328 @@
+//! 全屏的交互式题目浏览器，`leetcodecli tui`
+
+mod ui;
+
+use std::io::{self, Stdout};
+use std::panic;
+use std::path::PathBuf;
+use std::process::Command;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::cursor::Show;
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use tui::backend::CrosstermBackend;
+use tui::widgets::TableState;
+use tui::Terminal;
+use crate::leetcode::command::pick::PickPlugin;
+use crate::leetcode::error::Result;
+use crate::leetcode::markdown;
+use crate::leetcode::net::favorites;
+use crate::leetcode::net::problems_all::{ProblemsAll, StatStatus};
+use crate::leetcode::net::question_data::QuestionData;
+
+type Backend = CrosstermBackend<Stdout>;
+
+/// 按完成状态过滤
+#[derive(Clone, Copy, PartialEq)]
+enum StatusFilter {
+    All,
+    Solved,
+    Unsolved,
+}
+
+pub struct Browser {
+    problems_all: ProblemsAll,
+    /// 满足过滤条件的题目在`stat_status_pairs`中的下标
+    filtered: Vec<usize>,
+    table_state: TableState,
+    search: String,
+    searching: bool,
+    difficulty: Option<i32>,
+    status: StatusFilter,
+    starred_only: bool,
+    /// 预览的题目id和描述
+    preview: Option<(i32, String)>,
+    preview_scroll: u16,
+    /// 显示在状态栏中的提示
+    message: String,
+}
+
+pub async fn run() -> Result<()> {
+    let mut browser = Browser::new(ProblemsAll::fetch().await?);
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    // panic时先恢复终端，否则终端会停留在raw模式和备用屏幕中，也看不到panic信息
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, Show);
+        default_hook(info);
+    }));
+    enter(&mut terminal)?;
+    let res = browser.event_loop(&mut terminal).await;
+    leave(&mut terminal)?;
+    // 换回默认的hook
+    let _ = panic::take_hook();
+    res
+}
+
+fn enter(terminal: &mut Terminal<Backend>) -> Result<()> {
+    enable_raw_mode()?;
+    execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+    terminal.hide_cursor()?;
+    terminal.clear()?;
+    Ok(())
+}
+
+fn leave(terminal: &mut Terminal<Backend>) -> Result<()> {
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+    Ok(())
+}
+
+impl Browser {
+    fn new(problems_all: ProblemsAll) -> Browser {
+        let mut browser = Browser {
+            problems_all,
+            filtered: Vec::new(),
+            table_state: TableState::default(),
+            search: String::new(),
+            searching: false,
+            difficulty: None,
+            status: StatusFilter::All,
+            starred_only: false,
+            preview: None,
+            preview_scroll: 0,
+            message: String::new(),
+        };
+        browser.apply_filter();
+        browser
+    }
+
+    async fn event_loop(&mut self, terminal: &mut Terminal<Backend>) -> Result<()> {
+        self.load_preview(false).await?;
+        loop {
+            terminal.draw(|f| ui::draw(f, self))?;
+            let key = match event::read()? {
+                Event::Key(key) => key,
+                _ => continue,
+            };
+            if self.searching {
+                self.handle_search_key(key);
+                self.load_preview(false).await?;
+                continue;
+            }
+            self.message.clear();
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+                KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+                KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+                KeyCode::PageDown => self.move_selection(20),
+                KeyCode::PageUp => self.move_selection(-20),
+                KeyCode::Home | KeyCode::Char('g') => self.select(0),
+                KeyCode::End | KeyCode::Char('G') => self.select(self.filtered.len().saturating_sub(1)),
+                KeyCode::Char('J') => self.preview_scroll = self.preview_scroll.saturating_add(1),
+                KeyCode::Char('K') => self.preview_scroll = self.preview_scroll.saturating_sub(1),
+                KeyCode::Char('/') => self.searching = true,
+                KeyCode::Char('d') => {
+                    self.difficulty = match self.difficulty {
+                        None => Some(1),
+                        Some(3) => None,
+                        Some(level) => Some(level + 1),
+                    };
+                    self.apply_filter();
+                }
+                KeyCode::Char('s') => {
+                    self.status = match self.status {
+                        StatusFilter::All => StatusFilter::Solved,
+                        StatusFilter::Solved => StatusFilter::Unsolved,
+                        StatusFilter::Unsolved => StatusFilter::All,
+                    };
+                    self.apply_filter();
+                }
+                KeyCode::Char('f') => {
+                    self.starred_only = !self.starred_only;
+                    self.apply_filter();
+                }
+                KeyCode::Enter => {
+                    self.load_preview(true).await?;
+                    continue;
+                }
+                KeyCode::Char('*') => {
+                    self.toggle_star().await;
+                    continue;
+                }
+                KeyCode::Char('p') => {
+                    if let Some(path) = self.pick(terminal).await? {
+                        self.message = format!("generated {}", path.display());
+                    }
+                    continue;
+                }
+                KeyCode::Char('e') => {
+                    if let Some(path) = self.pick(terminal).await? {
+                        self.open_editor(terminal, &path)?;
+                    }
+                    continue;
+                }
+                _ => continue,
+            }
+            self.load_preview(false).await?;
+        }
+    }
+
+    fn handle_search_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter => self.searching = false,
+            KeyCode::Esc => {
+                self.searching = false;
+                self.search.clear();
+            }
+            KeyCode::Backspace => {
+                self.search.pop();
+            }
+            KeyCode::Char(c) => self.search.push(c),
+            _ => return,
+        }
+        self.apply_filter();
+    }
+
+    fn matches(&self, ss: &StatStatus) -> bool {
+        if !self.search.is_empty() {
+            let search = self.search.to_lowercase();
+            if ss.stat.frontend_question_id != search
+                && !ss.stat.question_title.to_lowercase().contains(&search)
+                && !ss.stat.question_title_slug.contains(&search) {
+                return false;
+            }
+        }
+        let solved = ss.status.as_deref() == Some("ac");
+        self.difficulty.map_or(true, |level| ss.difficulty.level == level)
+            && (!self.starred_only || ss.is_favor)
+            && match self.status {
+                StatusFilter::All => true,
+                StatusFilter::Solved => solved,
+                StatusFilter::Unsolved => !solved,
+            }
+    }
+
+    fn apply_filter(&mut self) {
+        let selected = self.selected().map(|ss| ss.stat.question_id);
+        self.filtered = self.problems_all.stat_status_pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, ss)| self.matches(ss))
+            .map(|(i, _)| i)
+            .collect();
+        // 尽量保持选中同一道题
+        let index = selected
+            .and_then(|id| self.filtered.iter()
+                .position(|&i| self.problems_all.stat_status_pairs[i].stat.question_id == id))
+            .unwrap_or(0);
+        self.select(index);
+    }
+
+    fn select(&mut self, index: usize) {
+        if self.filtered.is_empty() {
+            self.table_state.select(None);
+        } else {
+            self.table_state.select(Some(index.min(self.filtered.len() - 1)));
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let current = self.table_state.selected().unwrap_or(0) as isize;
+        self.select((current + delta).max(0) as usize);
+    }
+
+    fn selected(&self) -> Option<&StatStatus> {
+        let index = *self.filtered.get(self.table_state.selected()?)?;
+        self.problems_all.stat_status_pairs.get(index)
+    }
+
+    /// 更新预览，`fetch`为false时只使用缓存
+    async fn load_preview(&mut self, fetch: bool) -> Result<()> {
+        let (question_id, slug) = match self.selected() {
+            Some(ss) => (ss.stat.question_id, ss.stat.question_title_slug.clone()),
+            None => {
+                self.preview = None;
+                return Ok(());
+            }
+        };
+        if self.preview.as_ref().is_some_and(|(id, _)| *id == question_id) && !fetch {
+            return Ok(());
+        }
+        self.preview_scroll = 0;
+        if !fetch && !QuestionData::is_cached(&slug).await? {
+            self.preview = Some((question_id, "press Enter to load the description".to_string()));
+            return Ok(());
+        }
+        let description = match QuestionData::fetch(&slug).await {
+            Ok(question_data) => {
+                let content = if !question_data.translated_content.is_empty() {
+                    &question_data.translated_content
+                } else {
+                    &question_data.content
+                };
+                markdown::html_to_markdown(content)
+            }
+            Err(e) => format!("fail to fetch the description: {:?}", e),
+        };
+        self.preview = Some((question_id, description));
+        Ok(())
+    }
+
+    async fn toggle_star(&mut self) {
+        let (question_id, starred) = match self.selected() {
+            Some(ss) => (ss.stat.question_id, !ss.is_favor),
+            None => return,
+        };
+        let res = match favorites::set_starred(question_id, starred).await {
+            Ok(()) => {
+                self.problems_all.set_favor(question_id, starred);
+                self.problems_all.store().await
+            }
+            Err(e) => Err(e),
+        };
+        self.message = match res {
+            Ok(()) if starred => "starred".to_string(),
+            Ok(()) => "unstarred".to_string(),
+            Err(e) => format!("fail to star: {:?}", e),
+        };
+        self.apply_filter();
+    }
+
+    /// 离开全屏界面执行`pick`，文件已存在时的询问需要正常的终端
+    async fn pick(&mut self, terminal: &mut Terminal<Backend>) -> Result<Option<PathBuf>> {
+        let question_id = match self.selected() {
+            Some(ss) => ss.stat.question_id,
+            None => return Ok(None),
+        };
+        leave(terminal)?;
+        let mut pick_plugin = PickPlugin::new();
+        let res = async {
+            pick_plugin.fetch_problems_all().await?;
+            pick_plugin.fetch_question_data(question_id, false).await?;
+            pick_plugin.save_to_file(None, None, false).await
+        }.await;
+        enter(terminal)?;
+        match res {
+            Ok(path) => Ok(Some(path)),
+            Err(e) => {
+                self.message = format!("fail to pick: {:?}", e);
+                Ok(None)
+            }
+        }
+    }
+
+    fn open_editor(&mut self, terminal: &mut Terminal<Backend>, path: &PathBuf) -> Result<()> {
+        let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+        leave(terminal)?;
+        let status = Command::new(&editor).arg(path).status();
+        enter(terminal)?;
+        if let Err(e) = status {
+            self.message = format!("fail to run {}: {}", editor, e);
+        }
+        Ok(())
+    }
+}
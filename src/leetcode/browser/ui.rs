@@ -0,0 +1,118 @@
+use tui::backend::Backend;
+use tui::layout::{Constraint, Direction, Layout};
+use tui::style::{Color, Modifier, Style};
+use tui::text::{Span, Spans};
+use tui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap};
+use tui::Frame;
+use crate::leetcode::term::icon::Icon;
+use super::{Browser, StatusFilter};
+
+pub(super) fn draw<B: Backend>(f: &mut Frame<B>, browser: &mut Browser) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(f.size());
+    let main = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(chunks[0]);
+
+    draw_table(f, browser, main[0]);
+    draw_preview(f, browser, main[1]);
+    draw_status_bar(f, browser, chunks[1]);
+}
+
+fn difficulty_style(level: i32) -> Style {
+    let color = match level {
+        1 => Color::Green,
+        2 => Color::Yellow,
+        _ => Color::Red,
+    };
+    Style::default().fg(color)
+}
+
+fn draw_table<B: Backend>(f: &mut Frame<B>, browser: &mut Browser, area: tui::layout::Rect) {
+    let rows = browser.filtered.iter().map(|&i| {
+        let ss = &browser.problems_all.stat_status_pairs[i];
+        let status = match ss.status.as_deref() {
+            Some("ac") => Span::styled(Icon::Yes.to_string(), Style::default().fg(Color::Green)),
+            Some(_) => Span::styled(Icon::No.to_string(), Style::default().fg(Color::Red)),
+            None => Span::raw(Icon::Empty.to_string()),
+        };
+        let star = if ss.is_favor {
+            Span::styled(Icon::Star.to_string(), Style::default().fg(Color::Yellow))
+        } else {
+            Span::raw(Icon::Empty.to_string())
+        };
+        let lock = if ss.paid_only {
+            Span::styled(Icon::Lock.to_string(), Style::default().fg(Color::Red))
+        } else {
+            Span::raw(Icon::Empty.to_string())
+        };
+        let ac_rate = if ss.stat.total_submitted > 0 {
+            format!("{:.1}%", ss.stat.total_acs as f64 * 100.0 / ss.stat.total_submitted as f64)
+        } else {
+            String::new()
+        };
+        Row::new(vec![
+            Cell::from(Spans::from(vec![star, lock, status])),
+            Cell::from(ss.stat.frontend_question_id.clone()),
+            Cell::from(ss.stat.question_title.clone()),
+            Cell::from(Span::styled(ss.difficulty.name(), difficulty_style(ss.difficulty.level))),
+            Cell::from(ac_rate),
+        ])
+    }).collect::<Vec<_>>();
+
+    let title = format!(" problems ({}/{}) ", browser.filtered.len(), browser.problems_all.stat_status_pairs.len());
+    let table = Table::new(rows)
+        .header(Row::new(vec!["", "id", "title", "difficulty", "ac"])
+            .style(Style::default().add_modifier(Modifier::BOLD)))
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .widths(&[
+            Constraint::Length(6),
+            Constraint::Length(6),
+            Constraint::Min(20),
+            Constraint::Length(10),
+            Constraint::Length(6),
+        ]);
+    f.render_stateful_widget(table, area, &mut browser.table_state);
+}
+
+fn draw_preview<B: Backend>(f: &mut Frame<B>, browser: &Browser, area: tui::layout::Rect) {
+    let (title, text) = match (browser.selected(), &browser.preview) {
+        (Some(ss), Some((_, description))) => (
+            format!(" {}. {} ", ss.stat.frontend_question_id, ss.stat.question_title),
+            description.as_str(),
+        ),
+        _ => (" description ".to_string(), ""),
+    };
+    let paragraph = Paragraph::new(text)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .wrap(Wrap { trim: false })
+        .scroll((browser.preview_scroll, 0));
+    f.render_widget(paragraph, area);
+}
+
+fn draw_status_bar<B: Backend>(f: &mut Frame<B>, browser: &Browser, area: tui::layout::Rect) {
+    let text = if browser.searching {
+        format!("/{}", browser.search)
+    } else if !browser.message.is_empty() {
+        browser.message.clone()
+    } else {
+        let difficulty = match browser.difficulty {
+            None => "all",
+            Some(1) => "easy",
+            Some(2) => "medium",
+            Some(_) => "hard",
+        };
+        let status = match browser.status {
+            StatusFilter::All => "all",
+            StatusFilter::Solved => "solved",
+            StatusFilter::Unsolved => "unsolved",
+        };
+        format!("[d]ifficulty: {}  [s]tatus: {}  [f]avorite: {}  search: {}  | /:search enter:load J/K:scroll p:pick e:edit *:star q:quit",
+                difficulty, status, if browser.starred_only { "only" } else { "all" }, browser.search)
+    };
+    f.render_widget(Paragraph::new(text).style(Style::default().add_modifier(Modifier::REVERSED)), area);
+}
@@ -1,8 +1,12 @@
 use dirs::home_dir;
 use tokio::sync::Mutex;
-use leetcode_cache::database::DataBase;
+use leetcode_cache::batch::WriteBatch;
+use leetcode_cache::compression::Compression;
+use leetcode_cache::database::{DataBase, Stats};
+use leetcode_cache::options::Options;
 use lazy_static::lazy_static;
 
+use crate::leetcode::config::SITE;
 use crate::leetcode::error::Result;
 
 lazy_static! {
@@ -10,35 +14,139 @@ lazy_static! {
         let mut db_path = home_dir().expect("");
         db_path.push(".leetcode");
         db_path.push("db");
-        let db = DataBase::open(db_path).expect("fail to open db");
+        // 测试不能改动用户的数据，每次运行使用单独的临时目录
+        if cfg!(test) {
+            db_path = std::env::temp_dir().join(format!("leetcodecli-test-{}", std::process::id()));
+        }
+        // 题目列表等较大的value压缩后保存
+        let options = Options {
+            compression: Compression::Lz4,
+            ..Options::default()
+        };
+        let mut db = DataBase::open_with(db_path, options).expect("fail to open db");
+        migrate_legacy_keys(&mut db).expect("fail to migrate db");
         Mutex::new(db)
     };
 }
 
-pub(crate) async fn set(key: String, value: String) -> Result<()> {
-    DB.lock().await.set(key, value)?;
+/// 旧版本把所有数据都放在默认命名空间中，打开时移动到各自的命名空间
+fn migrate_legacy_keys(db: &mut DataBase) -> Result<()> {
+    let legacy_keys = [
+        (DB_TREES.auth, DB_KEYS.cookie),
+        (DB_TREES.problems, DB_KEYS.problems_all),
+        (DB_TREES.config, DB_KEYS.language),
+    ];
+    for (tree, key) in legacy_keys {
+        if let Some(value) = db.get(key)? {
+            db.tree(tree).set(key.to_string(), value)?;
+            db.remove(key)?;
+        }
+    }
     Ok(())
 }
 
-pub(crate) async fn get(key: &str) -> Result<Option<String>> {
-    let key = DB.lock().await.get(key)?;
+/// 与站点相关的数据的key，例如`leetcode.cn/two-sum`，不同站点的数据不会冲突
+pub(crate) fn site_key(key: &str) -> String {
+    format!("{}/{}", SITE, key)
+}
+
+pub(crate) async fn set(tree: &str, key: String, value: String) -> Result<()> {
+    DB.lock().await.tree(tree).set(key, value)?;
+    Ok(())
+}
+
+pub(crate) async fn get(tree: &str, key: &str) -> Result<Option<String>> {
+    let key = DB.lock().await.tree(tree).get(key)?;
     Ok(key)
 }
 
-pub(crate) async fn remove(key: &str) -> Result<()> {
-    DB.lock().await.remove(key)?;
+pub(crate) async fn remove(tree: &str, key: &str) -> Result<()> {
+    DB.lock().await.tree(tree).remove(key)?;
+    Ok(())
+}
+
+
+/// 按key的顺序读取命名空间中当前站点的所有数据，返回的key不包含站点前缀
+pub(crate) async fn scan_site(tree: &str) -> Result<Vec<(String, String)>> {
+    let prefix = site_key("");
+    let mut db = DB.lock().await;
+    let entries = db.tree(tree)
+        .scan_prefix(&prefix)
+        .map(|entry| entry.map(|(key, value)| (key[prefix.len()..].to_string(), value)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(entries)
+}
+
+/// 原子地写入多条数据
+pub(crate) async fn write(batch: WriteBatch) -> Result<()> {
+    DB.lock().await.write(batch)?;
+    Ok(())
+}
+
+pub(crate) async fn clear(tree: &str) -> Result<()> {
+    DB.lock().await.tree(tree).clear()?;
     Ok(())
 }
 
+pub(crate) async fn stats() -> Result<Stats> {
+    let stats = DB.lock().await.stats()?;
+    Ok(stats)
+}
+
+pub(crate) async fn compact() -> Result<()> {
+    DB.lock().await.compact()?;
+    Ok(())
+}
+
+/// # Return
+/// (key的数量, 占用的字节数)
+pub(crate) async fn tree_size(tree: &str) -> (usize, u64) {
+    let mut db = DB.lock().await;
+    let tree = db.tree(tree);
+    (tree.len(), tree.size())
+}
+
 pub struct DBKeys<'a> {
     pub cookie: &'a str,
     pub problems_all: &'a str,
     pub language: &'a str,
+    pub tag_index: &'a str,
 }
 
 pub const DB_KEYS: DBKeys<'static> = DBKeys {
     cookie: "Cookie",
     problems_all: "ProblemsAll",
     language: "Language",
+    tag_index: "TagIndex",
+};
+
+/// 数据库中的命名空间，`cache clear <tree>`只会清空对应的命名空间
+pub struct DBTrees<'a> {
+    pub auth: &'a str,
+    pub problems: &'a str,
+    pub config: &'a str,
+    pub submissions: &'a str,
+    pub questions: &'a str,
+    pub hints: &'a str,
+    pub plans: &'a str,
+    pub notes: &'a str,
+    pub reviews: &'a str,
+}
+
+pub const DB_TREES: DBTrees<'static> = DBTrees {
+    auth: "auth",
+    problems: "problems",
+    config: "config",
+    submissions: "submissions",
+    questions: "questions",
+    hints: "hints",
+    plans: "plans",
+    notes: "notes",
+    reviews: "reviews",
 };
 
+impl<'a> DBTrees<'a> {
+    pub fn all(&self) -> Vec<&'a str> {
+        vec![self.auth, self.problems, self.config, self.submissions, self.questions, self.hints, self.plans, self.notes, self.reviews]
+    }
+}
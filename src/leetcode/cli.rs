@@ -24,8 +24,50 @@ enum Commands {
     /// pick a problem
     Pick(Pick),
 
+    /// show the details of a problem
+    Show(Show),
+
+    /// reveal the hints of a problem one at a time
+    Hint(Hint),
+
+    /// show today's question
+    Daily(Daily),
+
+    /// browse problems in an interactive terminal UI
+    Tui,
+
+    /// show the progress and submission statistics
+    Stat(Stat),
+
+    /// add a problem to the default favorite list
+    Star(Star),
+
+    /// remove a problem from the default favorite list
+    Unstar(Star),
+
+    /// manage favorite lists
+    Favorites(Favorites),
+
+    /// work through study plans and problem lists
+    Plan(Plan),
+
+    /// show or edit the personal note of a problem
+    Note(Note),
+
+    /// review solved problems with spaced repetition
+    Review(Review),
+
+    /// list or read community solutions of a problem
+    Solutions(Solutions),
+
+    /// export accepted solutions into a directory
+    Export(Export),
+
     /// submit your answer
     Submit(Submit),
+
+    /// manage local cache
+    Cache(Cache),
 }
 
 #[derive(Debug, Args)]
@@ -51,17 +93,87 @@ enum AuthCommands {
     },
 }
 
+#[derive(Debug, Args)]
+struct Cache {
+    #[clap(subcommand)]
+    command: CacheCommands,
+}
+
+#[derive(Debug, Subcommand)]
+enum CacheCommands {
+    /// clear one namespace of the cache
+    Clear {
+        /// cache namespace, see `cache stats`
+        tree: String,
+    },
+
+    /// show the size of each namespace and how much data is stale
+    Stats,
+
+    /// rewrite the cache files without stale data
+    Compact,
+
+    /// download question descriptions for offline use
+    Prefetch(Prefetch),
+}
+
+#[derive(Debug, Args)]
+pub struct Prefetch {
+    /// download problems with this tag, e.g. `dynamic-programming`, can be repeated
+    #[clap(short, long, required_unless_present = "all")]
+    pub tag: Vec<String>,
+
+    /// download all free problems
+    #[clap(short, long)]
+    pub all: bool,
+
+    /// number of concurrent downloads
+    #[clap(short, long, default_value_t = 4)]
+    pub jobs: usize,
+
+    /// download again even if already cached
+    #[clap(long)]
+    pub refresh: bool,
+}
+
 #[derive(Debug, Args)]
 pub struct List {
-    /// filter by keyword
+    #[clap(flatten)]
+    pub filter: FilterArgs,
+
+    /// order by `id`, `title`, `difficulty`
+    #[clap(short, long, possible_values = ["id", "title", "difficulty"])]
+    pub order: Option<String>,
+
+    /// columns to show, separated by commas
+    #[clap(long, use_value_delimiter = true,
+           possible_values = ["star", "note", "paid", "status", "id", "title", "difficulty", "ac", "frequency"])]
+    pub columns: Option<Vec<String>>,
+
+    /// show at most `limit` problems
+    #[clap(long)]
+    pub limit: Option<usize>,
+
+    /// skip the first `offset` problems
+    #[clap(long, default_value_t = 0)]
+    pub offset: usize,
+
+    /// do not pipe the output into $PAGER
+    #[clap(long)]
+    pub no_pager: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct FilterArgs {
+    /// filter by id, title or slug
     #[clap(short, long)]
     pub keyword: Option<String>,
 
-    /// filter by tag
+    /// filter by tag slug or name, e.g. `dynamic-programming`
     #[clap(short, long)]
     pub tag: Option<String>,
 
-    /// filter by difficulty
+    /// filter by difficulty: easy, medium or hard
     #[clap(short, long)]
     pub difficulty: Option<String>,
 
@@ -76,20 +188,292 @@ pub struct List {
     /// filter by finish status
     #[clap(short, long)]
     pub finish: Option<bool>,
+}
+
+#[derive(Debug, Args)]
+pub struct Pick {
+    /// the id of the question
+    #[clap(required_unless_present = "random")]
+    pub question_id: Option<i32>,
 
-    /// order by `problem id`, `title`, `difficulty`
+    /// programming language to write answer
     #[clap(short, long)]
-    pub order: Option<String>,
+    pub language: Option<String>,
+
+    /// fetch the question again instead of using the cached one
+    #[clap(long)]
+    pub refresh: bool,
+
+    /// overwrite the existing file without asking
+    #[clap(short, long)]
+    pub force: bool,
+
+    /// back up the existing file and start a fresh attempt
+    #[clap(long, conflicts_with = "force")]
+    pub fresh: bool,
+
+    /// also write the full description to <id>-<slug>.md
+    #[clap(long)]
+    pub readme: bool,
+
+    #[clap(flatten)]
+    pub random: RandomArgs,
+}
+
+#[derive(Debug, Default, Args)]
+pub struct RandomArgs {
+    /// pick a random problem instead of the given id
+    #[clap(long, conflicts_with = "question-id")]
+    pub random: bool,
+
+    /// only pick problems of this difficulty: easy, medium or hard
+    #[clap(long, requires = "random", conflicts_with = "question-id")]
+    pub difficulty: Option<String>,
+
+    /// only pick problems with this tag, e.g. `dynamic-programming`
+    #[clap(long, requires = "random", conflicts_with = "question-id")]
+    pub tag: Option<String>,
+
+    /// only pick problems that are not accepted yet
+    #[clap(long, requires = "random", conflicts_with = "question-id")]
+    pub unsolved: bool,
+
+    /// also pick paid-only problems without a premium subscription
+    #[clap(long, requires = "random", conflicts_with = "question-id")]
+    pub include_locked: bool,
+
+    /// prefer problems with higher frequency
+    #[clap(long, requires = "random", conflicts_with = "question-id")]
+    pub weighted: bool,
+}
+
+impl RandomArgs {
+    /// 不是会员时跳过会员题
+    pub fn filter_args(&self, premium: bool) -> FilterArgs {
+        FilterArgs {
+            keyword: None,
+            tag: self.tag.clone(),
+            difficulty: self.difficulty.clone(),
+            star: None,
+            lock: if self.include_locked || premium { None } else { Some(false) },
+            finish: if self.unsolved { Some(false) } else { None },
+        }
+    }
 }
 
 #[derive(Debug, Args)]
-pub struct Pick {
+pub struct Show {
     /// the id of the question
     pub question_id: i32,
 
-    /// programming language to write answer
+    /// also show the hints
+    #[clap(long)]
+    pub hints: bool,
+
+    /// fetch the question again instead of using the cached one
+    #[clap(long)]
+    pub refresh: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct Hint {
+    /// the id of the question
+    pub question_id: i32,
+
+    /// reveal hints up to the n-th one, default to the next one
+    pub n: Option<usize>,
+
+    /// forget the revealed hints
+    #[clap(long)]
+    pub reset: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct Star {
+    /// the id of the question
+    pub question_id: i32,
+}
+
+#[derive(Debug, Args)]
+struct Favorites {
+    #[clap(subcommand)]
+    command: FavoritesCommands,
+}
+
+#[derive(Debug, Subcommand)]
+enum FavoritesCommands {
+    /// list all favorite lists, the starred one is the default list
+    List,
+
+    /// create a favorite list with its first question
+    Create {
+        /// name of the new list
+        name: String,
+
+        /// the id of the first question
+        question_id: i32,
+
+        /// make the list public
+        #[clap(long)]
+        public: bool,
+    },
+
+    /// add a question to a favorite list
+    Add {
+        /// name or id hash of the list
+        list: String,
+
+        /// the id of the question
+        question_id: i32,
+    },
+
+    /// remove a question from a favorite list
+    Remove {
+        /// name or id hash of the list
+        list: String,
+
+        /// the id of the question
+        question_id: i32,
+    },
+}
+
+#[derive(Debug, Args)]
+struct Plan {
+    #[clap(subcommand)]
+    command: PlanCommands,
+}
+
+#[derive(Debug, Subcommand)]
+enum PlanCommands {
+    /// list common study plans and downloaded plans and problem lists
+    List,
+
+    /// show the problems of a plan and the progress
+    Show {
+        /// slug of the plan, e.g. `leetcode-75`, or id_hash of a problem list
+        plan: String,
+
+        /// fetch the plan again instead of using the cached one
+        #[clap(short, long)]
+        refresh: bool,
+    },
+
+    /// pick the next unsolved problem of a plan
+    PickNext {
+        /// slug of the plan, e.g. `leetcode-75`, or id_hash of a problem list
+        plan: String,
+
+        /// programming language to write answer
+        #[clap(short, long)]
+        language: Option<String>,
+
+        /// overwrite the existing file without asking
+        #[clap(short, long)]
+        force: bool,
+    },
+}
+
+#[derive(Debug, Args)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Note {
+    #[clap(subcommand)]
+    command: Option<NoteCommands>,
+
+    /// the id of the question
+    #[clap(required = true)]
+    question_id: Option<i32>,
+
+    /// open the note in $EDITOR
+    #[clap(short, long)]
+    edit: bool,
+}
+
+#[derive(Debug, Subcommand)]
+enum NoteCommands {
+    /// search all notes
+    Search {
+        /// text to search, case insensitive
+        query: String,
+    },
+}
+
+#[derive(Debug, Args)]
+struct Review {
+    #[clap(subcommand)]
+    command: ReviewCommands,
+}
+
+#[derive(Debug, Subcommand)]
+enum ReviewCommands {
+    /// list the problems due for review
+    Today {
+        /// pick the first due problem, the existing file is backed up
+        #[clap(short, long)]
+        pick: bool,
+
+        /// programming language to write answer, used with --pick
+        #[clap(short, long)]
+        language: Option<String>,
+    },
+
+    /// rate a review and schedule the next one
+    Done {
+        /// the id of the question
+        question_id: i32,
+
+        /// how well you remembered the solution, from 0 to 5
+        #[clap(possible_values = ["0", "1", "2", "3", "4", "5"])]
+        grade: u8,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct Solutions {
+    /// the id of the question
+    pub question_id: i32,
+
+    /// read the n-th solution in the list
+    pub n: Option<usize>,
+
+    /// only keep code blocks in this language, e.g. `cpp`, `python3`
+    #[clap(short, long)]
+    pub lang: Option<String>,
+
+    /// number of solutions to list
+    #[clap(long, default_value_t = 10)]
+    pub limit: usize,
+}
+
+#[derive(Debug, Args)]
+pub struct Export {
+    /// the directory to export into, created if missing
+    pub dir: std::path::PathBuf,
+
+    /// download every solution again, even if unchanged
+    #[clap(short, long)]
+    pub force: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct Stat {
+    /// number of weeks shown in the submission calendar
+    #[clap(short, long, default_value_t = 26)]
+    pub weeks: usize,
+}
+
+#[derive(Debug, Args)]
+pub struct Daily {
+    /// pick today's question
+    #[clap(short, long)]
+    pub pick: bool,
+
+    /// programming language to write answer, used with --pick
     #[clap(short, long)]
     pub language: Option<String>,
+
+    /// overwrite the existing file without asking, used with --pick
+    #[clap(short, long)]
+    pub force: bool,
 }
 
 #[derive(Debug, Args)]
@@ -100,6 +484,10 @@ pub struct Submit {
     /// test mode, "\n" in test_data will be turn into new line
     #[clap(short, long)]
     pub test_data: Option<String>,
+
+    /// how well you remembered the solution, from 0 to 5, used to schedule reviews
+    #[clap(short, long, possible_values = ["0", "1", "2", "3", "4", "5"])]
+    pub grade: Option<u8>,
 }
 
 pub async fn process() -> Result<()> {
@@ -119,32 +507,106 @@ pub async fn process() -> Result<()> {
             }
         }
         Commands::List(list) => {
-            if let Some(k) = list.keyword {
-                println!("list keyword: {}", k);
-            }
-            if let Some(t) = list.tag {
-                println!("list tag: {}", t);
-            }
-            if let Some(s) = list.star {
-                println!("list star: {}", s);
-            }
-            if let Some(d) = list.difficulty {
-                println!("list difficulty: {}", d);
+            app.list_problems(list).await?;
+        }
+        Commands::Pick(pick) => {
+            app.pick_problem(pick).await?;
+        }
+        Commands::Show(show) => {
+            app.show_problem(show).await?;
+        }
+        Commands::Hint(hint) => {
+            app.show_hints(hint).await?;
+        }
+        Commands::Daily(daily) => {
+            app.daily(daily).await?;
+        }
+        Commands::Tui => {
+            app.browse().await?;
+        }
+        Commands::Stat(stat) => {
+            app.stat(stat).await?;
+        }
+        Commands::Star(star) => {
+            app.star(star.question_id, true).await?;
+        }
+        Commands::Unstar(star) => {
+            app.star(star.question_id, false).await?;
+        }
+        Commands::Plan(plan) => {
+            match plan.command {
+                PlanCommands::List => {
+                    app.list_plans().await?;
+                }
+                PlanCommands::Show { plan, refresh } => {
+                    app.show_plan(&plan, refresh).await?;
+                }
+                PlanCommands::PickNext { plan, language, force } => {
+                    app.pick_next_in_plan(&plan, language, force).await?;
+                }
             }
-            if let Some(l) = list.lock {
-                println!("list lock: {}", l);
+        }
+        Commands::Note(note) => {
+            match note.command {
+                Some(NoteCommands::Search { query }) => {
+                    app.search_notes(&query).await?;
+                }
+                None => {
+                    app.note(note.question_id.expect("question id is required"), note.edit).await?;
+                }
             }
-            if let Some(o) = list.order {
-                print!("list order: {}", o);
+        }
+        Commands::Review(review) => {
+            match review.command {
+                ReviewCommands::Today { pick, language } => {
+                    app.review_today(pick, language).await?;
+                }
+                ReviewCommands::Done { question_id, grade } => {
+                    app.review_done(question_id, grade).await?;
+                }
             }
-            app.list_problems().await?;
         }
-        Commands::Pick(pick) => {
-            app.pick_problem(pick).await?;
+        Commands::Solutions(solutions) => {
+            app.solutions(solutions).await?;
+        }
+        Commands::Export(export) => {
+            app.export(export).await?;
+        }
+        Commands::Favorites(favorites) => {
+            match favorites.command {
+                FavoritesCommands::List => {
+                    app.list_favorites().await?;
+                }
+                FavoritesCommands::Create { name, question_id, public } => {
+                    app.create_favorite(&name, public, question_id).await?;
+                }
+                FavoritesCommands::Add { list, question_id } => {
+                    app.add_favorite(&list, question_id).await?;
+                }
+                FavoritesCommands::Remove { list, question_id } => {
+                    app.remove_favorite(&list, question_id).await?;
+                }
+            }
         }
         Commands::Submit(submit) => {
             app.sumbit(submit).await?;
         }
+        Commands::Cache(cache) => {
+            match cache.command {
+                CacheCommands::Clear { tree } => {
+                    app.clear_cache(tree).await?;
+                }
+                CacheCommands::Stats => {
+                    app.show_cache_stats().await?;
+                }
+                CacheCommands::Compact => {
+                    app.compact_cache().await?;
+                }
+                CacheCommands::Prefetch(prefetch) => {
+                    app.prefetch(prefetch).await?;
+                }
+            }
+        }
     }
     Ok(())
 }
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use async_trait::async_trait;
 use cookie::{Cookie, CookieJar};
 use log::debug;
-use crate::leetcode::cache::DB_KEYS;
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
 
 use crate::leetcode::error::Result;
 
@@ -41,13 +41,13 @@ impl CookieAuthPlugin {
         std::io::stdin()
             .read_line(&mut session)
             .expect("fail to read session");
-        session = format!(r#"{}"#, session.trim_end());
+        session = session.trim_end().to_string();
         debug!("session: {}", session);
         println!("Enter csrftoken:");
         std::io::stdin()
             .read_line(&mut csrftoken)
             .expect("fail to read csrftoken");
-        csrftoken = format!(r#"{}"#, csrftoken.trim_end());
+        csrftoken = csrftoken.trim_end().to_string();
         debug!("csrftoken: {}", csrftoken);
         let mut jar = cookie::CookieJar::new();
         jar.add(Cookie::new("LEETCODE_SESSION", session));
@@ -58,12 +58,12 @@ impl CookieAuthPlugin {
     }
 
     async fn store_cookie(&mut self) -> Result<()> {
-        crate::leetcode::cache::set(DB_KEYS.cookie.to_string(), self.cookie.clone()).await?;
+        crate::leetcode::cache::set(DB_TREES.auth, DB_KEYS.cookie.to_string(), self.cookie.clone()).await?;
         Ok(())
     }
 
     async fn remove_cookie(&self) -> Result<()> {
-        crate::leetcode::cache::remove(DB_KEYS.cookie).await?;
+        crate::leetcode::cache::remove(DB_TREES.auth, DB_KEYS.cookie).await?;
         Ok(())
     }
 }
@@ -86,6 +86,7 @@ impl AuthPlugin for CookieAuthPlugin {
     }
 }
 
+#[allow(dead_code)]
 pub struct GitAuthPlugin {
     cookie: String,
 }
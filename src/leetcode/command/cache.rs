@@ -0,0 +1,107 @@
+use std::sync::Arc;
+use anyhow::anyhow;
+use tokio::sync::Semaphore;
+use crate::leetcode::cache::DB_TREES;
+use crate::leetcode::cli::Prefetch;
+use crate::leetcode::error::{LeetcodeError, Result};
+use crate::leetcode::net::problems_all::ProblemsAll;
+use crate::leetcode::net::problemset::ProblemsetQuestion;
+use crate::leetcode::net::tag_index::TagIndex;
+use crate::leetcode::net::question_data::QuestionData;
+
+pub struct CachePlugin {}
+
+impl CachePlugin {
+    pub fn new() -> CachePlugin {
+        CachePlugin {}
+    }
+
+    pub async fn clear(&self, tree: &str) -> Result<()> {
+        if !DB_TREES.all().contains(&tree) {
+            return Err(LeetcodeError::Any(anyhow!("unknown cache namespace: {}, expected one of {}",
+                                                  tree, DB_TREES.all().join(", "))));
+        }
+        let (len, size) = crate::leetcode::cache::tree_size(tree).await;
+        crate::leetcode::cache::clear(tree).await?;
+        println!("cleared {}: {} entries, {} bytes", tree, len, size);
+        Ok(())
+    }
+
+    pub async fn stats(&self) -> Result<()> {
+        let stats = crate::leetcode::cache::stats().await?;
+        println!("log files:  {}", stats.log_files);
+        println!("disk size:  {} bytes", stats.disk_size);
+        println!("live data:  {} bytes", stats.live_data);
+        println!("stale data: {} bytes", stats.stale_data);
+        for (tree, tree_stats) in &stats.trees {
+            let name = if tree.is_empty() { "(default)" } else { tree.as_str() };
+            println!("  {:12} {:6} entries {:10} bytes", name, tree_stats.keys, tree_stats.size);
+        }
+        Ok(())
+    }
+
+    pub async fn compact(&self) -> Result<()> {
+        let before = crate::leetcode::cache::stats().await?.disk_size;
+        crate::leetcode::cache::compact().await?;
+        let after = crate::leetcode::cache::stats().await?.disk_size;
+        println!("compacted: {} bytes -> {} bytes", before, after);
+        Ok(())
+    }
+
+    /// 并发下载题目描述，最多同时进行`prefetch.jobs`个请求
+    pub async fn prefetch(&self, prefetch: Prefetch) -> Result<()> {
+        let mut slugs = Vec::new();
+        if prefetch.all {
+            slugs.extend(ProblemsAll::fetch().await?
+                .stat_status_pairs
+                .into_iter()
+                .filter(|ss| !ss.paid_only)
+                .map(|ss| ss.stat.question_title_slug));
+        }
+        for tag in &prefetch.tag {
+            let questions = ProblemsetQuestion::fetch_by_tag(tag).await?;
+            TagIndex::update(tag, &questions).await?;
+            slugs.extend(questions
+                .into_iter()
+                .filter(|q| !q.paid_only)
+                .map(|q| q.title_slug));
+        }
+        slugs.sort();
+        slugs.dedup();
+        if !prefetch.refresh {
+            let mut uncached = Vec::with_capacity(slugs.len());
+            for slug in slugs {
+                if !QuestionData::is_cached(&slug).await? {
+                    uncached.push(slug);
+                }
+            }
+            slugs = uncached;
+        }
+
+        let total = slugs.len();
+        let semaphore = Arc::new(Semaphore::new(prefetch.jobs.max(1)));
+        let handles = slugs.into_iter()
+            .map(|slug| {
+                let semaphore = semaphore.clone();
+                tokio::spawn(async move {
+                    let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+                    let res = QuestionData::refresh(&slug).await;
+                    (slug, res)
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut failed = 0;
+        for (i, handle) in handles.into_iter().enumerate() {
+            let (slug, res) = handle.await.expect("prefetch task panicked");
+            match res {
+                Ok(_) => println!("[{}/{}] {}", i + 1, total, slug),
+                Err(e) => {
+                    failed += 1;
+                    println!("[{}/{}] {} failed: {:?}", i + 1, total, slug, e);
+                }
+            }
+        }
+        println!("prefetched {} questions, {} failed", total - failed, failed);
+        Ok(())
+    }
+}
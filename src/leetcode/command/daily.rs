@@ -0,0 +1,57 @@
+use ansi_term::Color::{Green, Red, Yellow};
+use ansi_term::Style;
+use chrono::Local;
+use crate::leetcode::config::CONST_CONFIG;
+use crate::leetcode::error::Result;
+use crate::leetcode::net::daily::DailyQuestion;
+use crate::leetcode::record::{self, SubmissionRecord};
+
+pub struct DailyPlugin {
+    daily: Option<DailyQuestion>,
+}
+
+impl DailyPlugin {
+    pub fn new() -> DailyPlugin {
+        DailyPlugin {
+            daily: None,
+        }
+    }
+
+    pub async fn fetch_daily(&mut self) -> Result<()> {
+        self.daily = Some(DailyQuestion::fetch().await?);
+        Ok(())
+    }
+
+    /// 每日一题的内部id，用于`pick`
+    pub fn question_id(&self) -> i32 {
+        self.daily.as_ref()
+            .unwrap()
+            .question
+            .question_id
+            .parse()
+            .expect("invalid question id")
+    }
+
+    pub async fn show(&self) -> Result<()> {
+        let daily = self.daily.as_ref().unwrap();
+        let difficulty = match daily.question.difficulty.to_lowercase().as_str() {
+            "easy" => Green.paint("easy"),
+            "medium" => Yellow.paint("medium"),
+            _ => Red.paint("hard"),
+        };
+        println!("{}", Style::new().bold().paint(format!("daily question of {}", daily.date)));
+        println!("[{}] {} {}", daily.question.question_frontend_id, daily.display_title(), difficulty);
+        println!("{}{}/", CONST_CONFIG.url.leetcode.problems, daily.question.title_slug);
+
+        let records = SubmissionRecord::load_all().await?;
+        let today = Local::now().date_naive();
+        let solved_today = records.iter()
+            .any(|r| r.is_accepted() && r.question_title_slug == daily.question.title_slug && r.date() == today);
+        if solved_today {
+            println!("{}", Green.paint("solved today"));
+        }
+        let streak = record::streak(&records, today);
+        println!("streak: {} day(s)", streak);
+        Ok(())
+    }
+}
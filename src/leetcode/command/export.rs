@@ -0,0 +1,238 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use chrono::{Local, TimeZone};
+use serde::{Serialize, Deserialize};
+use crate::leetcode::config::CONST_CONFIG;
+use crate::leetcode::error::Result;
+use crate::leetcode::markdown;
+use crate::leetcode::net::problems_all::{ProblemsAll, StatStatus};
+use crate::leetcode::net::question_data::{QuestionData, TopicTag};
+use crate::leetcode::net::submission::SubmissionDetail;
+
+/// 记录已经导出的提交，再次导出时跳过没有变化的题目
+const MANIFEST: &str = ".leetcode-export.json";
+
+#[derive(Serialize, Deserialize)]
+struct ExportEntry {
+    frontend_id: String,
+    title: String,
+    difficulty: String,
+    lang: String,
+    submission_id: String,
+    /// 相对于导出目录的题目目录
+    dir: String,
+    solution: String,
+}
+
+/// 提交语言到文件扩展名
+fn extension(lang: &str) -> &'static str {
+    match lang {
+        "c" => "c",
+        "cpp" => "cpp",
+        "csharp" => "cs",
+        "java" => "java",
+        "python" | "python3" => "py",
+        "javascript" => "js",
+        "typescript" => "ts",
+        "golang" | "go" => "go",
+        "rust" => "rs",
+        "kotlin" => "kt",
+        "swift" => "swift",
+        "scala" => "scala",
+        "ruby" => "rb",
+        "php" => "php",
+        "dart" => "dart",
+        "racket" => "rkt",
+        "erlang" => "erl",
+        "elixir" => "ex",
+        "bash" => "sh",
+        "mysql" | "mssql" | "oraclesql" | "postgresql" => "sql",
+        _ => "txt",
+    }
+}
+
+/// frontend_question_id有些不是数字，例如`剑指 Offer 03`，排在数字之后
+fn id_order(frontend_id: &str) -> (u32, &str) {
+    (frontend_id.parse().unwrap_or(u32::MAX), frontend_id)
+}
+
+pub struct ExportPlugin {
+    problems_all: Option<ProblemsAll>,
+}
+
+impl ExportPlugin {
+    pub fn new() -> ExportPlugin {
+        ExportPlugin {
+            problems_all: None,
+        }
+    }
+
+    pub async fn fetch_problems_all(&mut self) -> Result<()> {
+        self.problems_all = Some(ProblemsAll::fetch().await?);
+        Ok(())
+    }
+
+    /// 导出所有通过的题目，`force`为false时跳过最近一次通过的提交没有变化的题目
+    pub async fn export(&self, dir: &Path, force: bool) -> Result<()> {
+        fs::create_dir_all(dir)?;
+        let manifest_path = dir.join(MANIFEST);
+        let mut manifest: BTreeMap<String, ExportEntry> = match fs::read_to_string(&manifest_path) {
+            Ok(content) => serde_json::from_str(&content)?,
+            Err(_) => BTreeMap::new(),
+        };
+
+        let solved = self.problems_all.as_ref()
+            .expect("fail to fetch problems")
+            .stat_status_pairs
+            .iter()
+            .filter(|ss| ss.status.as_deref() == Some("ac"))
+            .collect::<Vec<_>>();
+        let (mut exported, mut skipped, mut failed) = (0, 0, 0);
+        for (i, ss) in solved.iter().enumerate() {
+            let slug = &ss.stat.question_title_slug;
+            let progress = format!("[{}/{}] {}. {}", i + 1, solved.len(), ss.stat.frontend_question_id, ss.stat.question_title);
+            let submission_id = match SubmissionDetail::latest_accepted_id(slug).await {
+                Ok(Some(submission_id)) => submission_id,
+                Ok(None) => {
+                    println!("{}: no accepted submission found", progress);
+                    failed += 1;
+                    continue;
+                }
+                Err(e) => {
+                    println!("{}: {:?}", progress, e);
+                    failed += 1;
+                    continue;
+                }
+            };
+            let unchanged = manifest.get(slug).is_some_and(|entry| {
+                entry.submission_id == submission_id && dir.join(&entry.dir).join(&entry.solution).exists()
+            });
+            if unchanged && !force {
+                skipped += 1;
+                continue;
+            }
+            match self.export_problem(dir, ss, &submission_id).await {
+                Ok(entry) => {
+                    println!("{}: {}/{}", progress, entry.dir, entry.solution);
+                    manifest.insert(slug.clone(), entry);
+                    exported += 1;
+                }
+                Err(e) => {
+                    println!("{}: {:?}", progress, e);
+                    failed += 1;
+                }
+            }
+            // 每道题之后保存，中途退出时下次可以继续
+            fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)?;
+        }
+        fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)?;
+        fs::write(dir.join("README.md"), index(&manifest))?;
+        println!("exported {}, unchanged {}, failed {}, see {}",
+                 exported, skipped, failed, dir.join("README.md").display());
+        Ok(())
+    }
+
+    /// 写入`<difficulty>/<id>-<slug>/solution.<ext>`和README.md
+    async fn export_problem(&self, dir: &Path, ss: &StatStatus, submission_id: &str) -> Result<ExportEntry> {
+        let slug = &ss.stat.question_title_slug;
+        let detail = SubmissionDetail::fetch(submission_id).await?;
+        let question_data = QuestionData::fetch(slug).await?;
+
+        let problem_dir = PathBuf::from(ss.difficulty.name())
+            .join(format!("{}-{}", ss.stat.frontend_question_id, slug));
+        let solution = format!("solution.{}", extension(&detail.lang));
+        fs::create_dir_all(dir.join(&problem_dir))?;
+        fs::write(dir.join(&problem_dir).join(&solution), &detail.code)?;
+
+        let content = if !question_data.translated_content.is_empty() {
+            &question_data.translated_content
+        } else {
+            &question_data.content
+        };
+        let tags = question_data.topic_tags.iter().map(TopicTag::display_name).collect::<Vec<_>>();
+        let submitted = Local.timestamp_opt(detail.timestamp, 0)
+            .single()
+            .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+        let mut readme = format!("# {}. {}\n\n", ss.stat.frontend_question_id, ss.stat.question_title);
+        readme.push_str(&format!("- difficulty: {}\n", ss.difficulty.name()));
+        readme.push_str(&format!("- link: {}{}/\n", CONST_CONFIG.url.leetcode.problems, slug));
+        if !tags.is_empty() {
+            readme.push_str(&format!("- tags: {}\n", tags.join(", ")));
+        }
+        readme.push_str(&format!("- language: {}\n", detail.lang));
+        readme.push_str(&format!("- runtime: {}, memory: {}\n", detail.runtime, detail.memory));
+        readme.push_str(&format!("- submitted: {}\n", submitted));
+        readme.push_str(&format!("\n## Description\n\n{}\n", markdown::html_to_markdown(content).trim_end()));
+        readme.push_str(&format!("\n## Solution\n\n[{}]({})\n", solution, solution));
+        fs::write(dir.join(&problem_dir).join("README.md"), readme)?;
+
+        Ok(ExportEntry {
+            frontend_id: ss.stat.frontend_question_id.clone(),
+            title: ss.stat.question_title.clone(),
+            difficulty: ss.difficulty.name().to_string(),
+            lang: detail.lang,
+            submission_id: detail.id,
+            dir: problem_dir.to_string_lossy().replace('\\', "/"),
+            solution,
+        })
+    }
+}
+
+/// 导出目录的索引，按题号排列的markdown表格
+fn index(manifest: &BTreeMap<String, ExportEntry>) -> String {
+    let mut entries = manifest.values().collect::<Vec<_>>();
+    entries.sort_by(|a, b| id_order(&a.frontend_id).cmp(&id_order(&b.frontend_id)));
+    let mut content = format!("# LeetCode Solutions\n\n{} solution(s).\n\n", entries.len());
+    content.push_str("| # | Title | Difficulty | Language | Solution |\n");
+    content.push_str("|---|-------|------------|----------|----------|\n");
+    for entry in entries {
+        // 链接中不能有空格
+        let dir = entry.dir.replace(' ', "%20");
+        content.push_str(&format!("| {} | [{}]({}/README.md) | {} | {} | [{}]({}/{}) |\n",
+                                  entry.frontend_id,
+                                  entry.title.replace('|', "\\|"),
+                                  dir,
+                                  entry.difficulty,
+                                  entry.lang,
+                                  entry.solution,
+                                  dir,
+                                  entry.solution));
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use super::{index, ExportEntry};
+
+    fn entry(frontend_id: &str, title: &str, dir: &str) -> ExportEntry {
+        ExportEntry {
+            frontend_id: frontend_id.to_string(),
+            title: title.to_string(),
+            difficulty: "easy".to_string(),
+            lang: "rust".to_string(),
+            submission_id: "1".to_string(),
+            dir: dir.to_string(),
+            solution: "solution.rs".to_string(),
+        }
+    }
+
+    #[test]
+    fn index_table() {
+        let mut manifest = BTreeMap::new();
+        manifest.insert("a".to_string(), entry("剑指 Offer 03", "数组中重复的数字", "easy/剑指 Offer 03-shu-zu"));
+        manifest.insert("b".to_string(), entry("10", "Regular | Expression", "hard/10-regular"));
+        manifest.insert("c".to_string(), entry("2", "Add Two Numbers", "medium/2-add-two-numbers"));
+        let content = index(&manifest);
+        let rows = content.lines().filter(|line| line.starts_with("| ") && !line.starts_with("| #")).collect::<Vec<_>>();
+        assert!(content.contains("3 solution(s)."));
+        assert_eq!(rows, vec![
+            "| 2 | [Add Two Numbers](medium/2-add-two-numbers/README.md) | easy | rust | [solution.rs](medium/2-add-two-numbers/solution.rs) |",
+            "| 10 | [Regular \\| Expression](hard/10-regular/README.md) | easy | rust | [solution.rs](hard/10-regular/solution.rs) |",
+            "| 剑指 Offer 03 | [数组中重复的数字](easy/剑指%20Offer%2003-shu-zu/README.md) | easy | rust | [solution.rs](easy/剑指%20Offer%2003-shu-zu/solution.rs) |",
+        ]);
+    }
+}
@@ -0,0 +1,102 @@
+use anyhow::anyhow;
+use ansi_term::Color::Yellow;
+use ansi_term::Style;
+use crate::leetcode::error::{LeetcodeError, Result};
+use crate::leetcode::net::favorites::{self, FavoriteList};
+use crate::leetcode::net::problems_all::ProblemsAll;
+use crate::leetcode::term::icon::Icon;
+use crate::leetcode::term::text;
+
+pub struct FavoritesPlugin {}
+
+impl FavoritesPlugin {
+    pub fn new() -> FavoritesPlugin {
+        FavoritesPlugin {}
+    }
+
+    /// 加入或移出默认收藏夹，即题目列表中的★
+    pub async fn star(&self, question_id: i32, starred: bool) -> Result<()> {
+        favorites::set_starred(question_id, starred).await?;
+        store_favor(question_id, starred).await?;
+        if starred {
+            println!("starred {}", question_id);
+        } else {
+            println!("unstarred {}", question_id);
+        }
+        Ok(())
+    }
+
+    pub async fn list(&self) -> Result<()> {
+        let favorites = FavoriteList::fetch_all().await?;
+        let name_width = favorites.iter().map(|favorite| text::width(&favorite.name)).max().unwrap_or(0);
+        for (i, favorite) in favorites.iter().enumerate() {
+            let default = if i == 0 { Yellow.paint(Icon::Star.to_string()).to_string() } else { Icon::Empty.to_string() };
+            println!("{} {} {:4} problem(s)  {:7}  {}",
+                     default,
+                     Style::new().bold().paint(text::pad(&favorite.name, name_width)),
+                     favorite.questions.len(),
+                     if favorite.is_public_favorite { "public" } else { "private" },
+                     favorite.id_hash);
+        }
+        Ok(())
+    }
+
+    pub async fn create(&self, name: &str, is_public: bool, question_id: i32) -> Result<()> {
+        let id_hash = FavoriteList::create(name, is_public, question_id).await?;
+        println!("created {} ({})", name, id_hash);
+        Ok(())
+    }
+
+    /// `list`可以是收藏夹的名字或者id_hash
+    pub async fn add(&self, list: &str, question_id: i32) -> Result<()> {
+        let (favorite, is_default) = find(list).await?;
+        if favorite.contains(question_id) {
+            println!("{} is already in {}", question_id, favorite.name);
+            return Ok(());
+        }
+        self.update(&favorite, is_default, question_id, true).await
+    }
+
+    pub async fn remove(&self, list: &str, question_id: i32) -> Result<()> {
+        let (favorite, is_default) = find(list).await?;
+        if !favorite.contains(question_id) {
+            println!("{} is not in {}", question_id, favorite.name);
+            return Ok(());
+        }
+        self.update(&favorite, is_default, question_id, false).await
+    }
+
+    async fn update(&self, favorite: &FavoriteList, is_default: bool, question_id: i32, add: bool) -> Result<()> {
+        if add {
+            favorite.add(question_id).await?;
+        } else {
+            favorite.remove(question_id).await?;
+        }
+        if is_default {
+            store_favor(question_id, add).await?;
+        }
+        if add {
+            println!("added {} to {}", question_id, favorite.name);
+        } else {
+            println!("removed {} from {}", question_id, favorite.name);
+        }
+        Ok(())
+    }
+}
+
+/// 修改默认收藏夹后同步更新缓存的题目列表，`list --star`可以立即看到变化
+async fn store_favor(question_id: i32, is_favor: bool) -> Result<()> {
+    let mut problems_all = ProblemsAll::fetch().await?;
+    problems_all.set_favor(question_id, is_favor);
+    problems_all.store().await
+}
+
+/// 返回找到的收藏夹以及它是不是默认收藏夹
+async fn find(list: &str) -> Result<(FavoriteList, bool)> {
+    FavoriteList::fetch_all().await?
+        .into_iter()
+        .enumerate()
+        .find(|(_, favorite)| favorite.name == list || favorite.id_hash == list)
+        .map(|(i, favorite)| (favorite, i == 0))
+        .ok_or_else(|| LeetcodeError::Any(anyhow!("no favorite list named {}", list)))
+}
@@ -0,0 +1,90 @@
+use ansi_term::Style;
+use crate::leetcode::cache::DB_TREES;
+use crate::leetcode::error::Result;
+use crate::leetcode::markdown;
+use crate::leetcode::net::problems_all::ProblemsAll;
+use crate::leetcode::net::question_data::QuestionData;
+
+pub struct HintPlugin {
+    problems_all: Option<ProblemsAll>,
+    question_data: Option<QuestionData>,
+    question_title_slug: String,
+}
+
+impl HintPlugin {
+    pub fn new() -> HintPlugin {
+        HintPlugin {
+            problems_all: None,
+            question_data: None,
+            question_title_slug: "".to_string(),
+        }
+    }
+
+    pub async fn fetch_problems_all(&mut self) -> Result<()> {
+        self.problems_all = Some(ProblemsAll::fetch().await?);
+        Ok(())
+    }
+
+    pub async fn fetch_question_data(&mut self, question_id: i32) -> Result<()> {
+        self.question_title_slug = self.problems_all.as_ref()
+            .expect("fail to fetch problems")
+            .stat_status_pairs
+            .iter()
+            .find(|ss| ss.stat.question_id == question_id)
+            .expect("question id invalid")
+            .stat
+            .question_title_slug
+            .clone();
+        let mut question_data = QuestionData::fetch(&self.question_title_slug).await?;
+        // 旧版本缓存的题目没有提示
+        if question_data.hints.is_empty() && question_data.difficulty.is_empty() {
+            question_data = QuestionData::refresh(&self.question_title_slug).await?;
+        }
+        self.question_data = Some(question_data);
+        Ok(())
+    }
+
+    /// 显示前`n`个提示，`n`为`None`时多显示一个，已显示的数量保存在缓存中
+    pub async fn reveal(&self, n: Option<usize>) -> Result<()> {
+        let hints = &self.question_data.as_ref().unwrap().hints;
+        if hints.is_empty() {
+            println!("no hints for this problem");
+            return Ok(());
+        }
+        let revealed = self.revealed().await?;
+        let revealed = n.unwrap_or(revealed + 1).clamp(1, hints.len()).max(revealed);
+        crate::leetcode::cache::set(DB_TREES.hints, self.cache_key(), revealed.to_string()).await?;
+
+        let bold = Style::new().bold();
+        for (i, hint) in hints.iter().take(revealed).enumerate() {
+            println!("{}", bold.paint(format!("hint {}/{}:", i + 1, hints.len())));
+            println!("{}", markdown::wrap(&markdown::html_to_markdown(hint), 80));
+            println!();
+        }
+        if revealed < hints.len() {
+            println!("{} more hint(s), run again to reveal the next one", hints.len() - revealed);
+        } else {
+            println!("all hints revealed");
+        }
+        Ok(())
+    }
+
+    /// 还没有显示过提示时什么也不做
+    pub async fn reset(&self) -> Result<()> {
+        let key = self.cache_key();
+        if crate::leetcode::cache::get(DB_TREES.hints, &key).await?.is_some() {
+            crate::leetcode::cache::remove(DB_TREES.hints, &key).await?;
+        }
+        Ok(())
+    }
+
+    async fn revealed(&self) -> Result<usize> {
+        Ok(crate::leetcode::cache::get(DB_TREES.hints, &self.cache_key()).await?
+            .and_then(|val| val.parse().ok())
+            .unwrap_or(0))
+    }
+
+    fn cache_key(&self) -> String {
+        crate::leetcode::cache::site_key(&self.question_title_slug)
+    }
+}
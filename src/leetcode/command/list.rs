@@ -1,6 +1,113 @@
-use crate::leetcode::net::problems_all::ProblemsAll;
+use std::collections::HashSet;
+use std::env;
+use std::io::{self, IsTerminal, Write};
+use std::process::{Command, Stdio};
+use ansi_term::Color::{Green, Red, Yellow};
+use ansi_term::Style;
+use crate::leetcode::cli::List;
+use crate::leetcode::filter::Filter;
+use crate::leetcode::note;
+use crate::leetcode::net::problems_all::{ProblemsAll, StatStatus};
+use crate::leetcode::term::icon::Icon;
+use crate::leetcode::term::text;
 use crate::leetcode::error::Result;
 
+/// 不是终端时使用的宽度
+const DEFAULT_WIDTH: usize = 120;
+const MIN_TITLE_WIDTH: usize = 20;
+
+/// `list`可以显示的列
+#[derive(Clone, Copy, PartialEq)]
+pub enum Column {
+    Star,
+    Note,
+    Paid,
+    Status,
+    Id,
+    Title,
+    Difficulty,
+    Ac,
+    Frequency,
+}
+
+const DEFAULT_COLUMNS: [Column; 8] = [
+    Column::Star,
+    Column::Note,
+    Column::Paid,
+    Column::Status,
+    Column::Id,
+    Column::Title,
+    Column::Difficulty,
+    Column::Ac,
+];
+
+impl Column {
+    fn from_name(name: &str) -> Option<Column> {
+        Some(match name {
+            "star" => Column::Star,
+            "note" => Column::Note,
+            "paid" => Column::Paid,
+            "status" => Column::Status,
+            "id" => Column::Id,
+            "title" => Column::Title,
+            "difficulty" => Column::Difficulty,
+            "ac" => Column::Ac,
+            "frequency" => Column::Frequency,
+            _ => return None,
+        })
+    }
+
+    fn header(&self) -> &'static str {
+        match self {
+            Column::Star | Column::Note | Column::Paid | Column::Status => "",
+            Column::Id => "id",
+            Column::Title => "title",
+            Column::Difficulty => "difficulty",
+            Column::Ac => "ac",
+            Column::Frequency => "freq",
+        }
+    }
+
+    /// 不带颜色的内容，`noted`为题目是否有笔记
+    fn text(&self, ss: &StatStatus, noted: bool) -> String {
+        match self {
+            Column::Star if ss.is_favor => Icon::Star.to_string(),
+            Column::Note if noted => Icon::Note.to_string(),
+            Column::Paid if ss.paid_only => Icon::Lock.to_string(),
+            Column::Status => match ss.status.as_deref() {
+                Some("ac") => Icon::Yes.to_string(),
+                Some(_) => Icon::No.to_string(),
+                None => String::new(),
+            },
+            Column::Id => ss.stat.frontend_question_id.clone(),
+            Column::Title => ss.stat.question_title.clone(),
+            Column::Difficulty => ss.difficulty.name().to_string(),
+            Column::Ac if ss.stat.total_submitted > 0 => {
+                format!("{:.1}%", ss.stat.total_acs as f64 * 100.0 / ss.stat.total_submitted as f64)
+            }
+            Column::Frequency => ss.frequency.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// 先对齐再上色，颜色不影响宽度
+    fn render(&self, ss: &StatStatus, noted: bool, width: usize) -> String {
+        let text = text::fit(&self.text(ss, noted), width);
+        match self {
+            Column::Star => Yellow.paint(text).to_string(),
+            Column::Paid => Red.paint(text).to_string(),
+            Column::Status if ss.status.as_deref() == Some("ac") => Green.paint(text).to_string(),
+            Column::Status => Red.paint(text).to_string(),
+            Column::Difficulty => match ss.difficulty.level {
+                1 => Green.paint(text).to_string(),
+                2 => Yellow.paint(text).to_string(),
+                _ => Red.paint(text).to_string(),
+            },
+            _ => text,
+        }
+    }
+}
+
 pub struct ListPlugin {
     problems_all: Option<ProblemsAll>,
 }
@@ -17,13 +124,108 @@ impl ListPlugin {
         Ok(())
     }
 
-    pub async fn list_problems_all(&self) {
-        self.problems_all.as_ref()
+    /// 按`list.order`排序，默认按id排序，输出到终端且超过一屏时使用`$PAGER`分页
+    pub async fn list_problems_all(&self, filter: &Filter, list: &List) -> Result<()> {
+        let mut problems: Vec<&StatStatus> = self.problems_all.as_ref()
             .expect("fail to fetch problems")
             .stat_status_pairs
             .iter()
-            .for_each(|s| {
-                s.pretty_print();
-            });
+            .filter(|ss| filter.matches(ss))
+            .collect();
+        match list.order.as_deref() {
+            Some("title") => problems.sort_by(|a, b| a.stat.question_title.cmp(&b.stat.question_title)),
+            Some("difficulty") => problems.sort_by_key(|ss| ss.difficulty.level),
+            _ => {}
+        }
+        let problems: Vec<&StatStatus> = problems.into_iter()
+            .skip(list.offset)
+            .take(list.limit.unwrap_or(usize::MAX))
+            .collect();
+
+        let columns = match &list.columns {
+            Some(names) => names.iter().filter_map(|name| Column::from_name(name)).collect(),
+            None => DEFAULT_COLUMNS.to_vec(),
+        };
+        let noted = if columns.contains(&Column::Note) {
+            note::noted_slugs().await?
+        } else {
+            HashSet::new()
+        };
+        let is_tty = io::stdout().is_terminal();
+        let (term_width, term_height) = match crossterm::terminal::size() {
+            Ok((width, height)) if is_tty => (width as usize, height as usize),
+            _ => (DEFAULT_WIDTH, usize::MAX),
+        };
+        let widths = column_widths(&columns, &problems, &noted, term_width);
+
+        let mut lines = Vec::with_capacity(problems.len() + 1);
+        let header = columns.iter()
+            .zip(&widths)
+            .map(|(column, &width)| text::fit(column.header(), width))
+            .collect::<Vec<_>>()
+            .join(" ");
+        lines.push(Style::new().bold().paint(header.trim_end().to_string()).to_string());
+        for ss in problems {
+            let has_note = noted.contains(&ss.stat.question_title_slug);
+            let line = columns.iter()
+                .zip(&widths)
+                .map(|(column, &width)| column.render(ss, has_note, width))
+                .collect::<Vec<_>>()
+                .join(" ");
+            lines.push(line);
+        }
+
+        let output = lines.join("\n");
+        if is_tty && !list.no_pager && lines.len() >= term_height {
+            page(&output)
+        } else {
+            println!("{}", output);
+            Ok(())
+        }
+    }
+}
+
+/// 标题占用其它列剩下的宽度，其它列取内容的最大宽度
+fn column_widths(columns: &[Column], problems: &[&StatStatus], noted: &HashSet<String>, term_width: usize) -> Vec<usize> {
+    let mut widths: Vec<usize> = columns.iter()
+        .map(|column| {
+            problems.iter()
+                .map(|ss| text::width(&column.text(ss, noted.contains(&ss.stat.question_title_slug))))
+                .chain(std::iter::once(text::width(column.header())))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    if let Some(i) = columns.iter().position(|&column| column == Column::Title) {
+        let others: usize = widths.iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(_, width)| width + 1)
+            .sum();
+        widths[i] = widths[i].min(term_width.saturating_sub(others).max(MIN_TITLE_WIDTH));
+    }
+    widths
+}
+
+/// 把输出交给`$PAGER`（默认`less -R`），启动失败时直接输出
+fn page(output: &str) -> Result<()> {
+    let pager = env::var("PAGER").unwrap_or_else(|_| "less -R".to_string());
+    let mut args = pager.split_whitespace();
+    let child = match args.next() {
+        Some(program) => Command::new(program).args(args).stdin(Stdio::piped()).spawn(),
+        None => Err(io::Error::from(io::ErrorKind::NotFound)),
+    };
+    let mut child = match child {
+        Ok(child) => child,
+        Err(_) => {
+            println!("{}", output);
+            return Ok(());
+        }
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // 用户提前退出pager时会写入失败，忽略即可
+        let _ = writeln!(stdin, "{}", output);
     }
+    child.wait()?;
+    Ok(())
 }
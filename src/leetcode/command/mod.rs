@@ -1,4 +1,15 @@
 pub mod auth;
+pub mod cache;
+pub mod daily;
+pub mod export;
+pub mod favorites;
+pub mod hint;
 pub mod list;
+pub mod note;
 pub mod pick;
-pub mod submit;
\ No newline at end of file
+pub mod plan;
+pub mod review;
+pub mod show;
+pub mod solutions;
+pub mod stat;
+pub mod submit;
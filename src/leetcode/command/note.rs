@@ -0,0 +1,106 @@
+use std::fs;
+use std::process::Command;
+use anyhow::anyhow;
+use ansi_term::Color::Yellow;
+use ansi_term::Style;
+use crate::leetcode::error::{LeetcodeError, Result};
+use crate::leetcode::net::problems_all::{ProblemsAll, StatStatus};
+use crate::leetcode::note;
+
+pub struct NotePlugin {
+    problems_all: Option<ProblemsAll>,
+}
+
+impl NotePlugin {
+    pub fn new() -> NotePlugin {
+        NotePlugin {
+            problems_all: None,
+        }
+    }
+
+    pub async fn fetch_problems_all(&mut self) -> Result<()> {
+        self.problems_all = Some(ProblemsAll::fetch().await?);
+        Ok(())
+    }
+
+    fn stat_status(&self, question_id: i32) -> &StatStatus {
+        self.problems_all.as_ref()
+            .expect("fail to fetch problems")
+            .stat_status_pairs
+            .iter()
+            .find(|ss| ss.stat.question_id == question_id)
+            .expect("question id invalid")
+    }
+
+    pub async fn show(&self, question_id: i32) -> Result<()> {
+        let ss = self.stat_status(question_id);
+        match note::load(&ss.stat.question_title_slug).await? {
+            Some(content) => println!("{}", content),
+            None => println!("no note for [{}] {}, use --edit to write one",
+                             ss.stat.frontend_question_id, ss.stat.question_title),
+        }
+        Ok(())
+    }
+
+    /// 用`$EDITOR`（默认vi）编辑临时文件，保存后写回缓存，清空内容则删除笔记
+    pub async fn edit(&self, question_id: i32) -> Result<()> {
+        let ss = self.stat_status(question_id);
+        let slug = &ss.stat.question_title_slug;
+        let content = match note::load(slug).await? {
+            Some(content) => content,
+            None => format!("# [{}] {}\n\n", ss.stat.frontend_question_id, ss.stat.question_title),
+        };
+        let path = std::env::temp_dir().join(format!("leetcode-note-{}.md", slug));
+        fs::write(&path, &content)?;
+        let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+        let status = Command::new(&editor).arg(&path).status();
+        let edited = fs::read_to_string(&path);
+        fs::remove_file(&path)?;
+        if !status?.success() {
+            return Err(LeetcodeError::Any(anyhow!("{} exited with error, note not saved", editor)));
+        }
+        let edited = edited?;
+        if edited == content {
+            println!("note unchanged");
+            return Ok(());
+        }
+        note::save(slug, &edited).await?;
+        if edited.trim().is_empty() {
+            println!("note removed");
+        } else {
+            println!("note saved");
+        }
+        Ok(())
+    }
+
+    /// 不区分大小写地搜索所有笔记，输出匹配的行
+    pub async fn search(&self, query: &str) -> Result<()> {
+        let query = query.to_lowercase();
+        let problems = &self.problems_all.as_ref().expect("fail to fetch problems").stat_status_pairs;
+        let mut notes = note::load_all().await?;
+        notes.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut found = 0;
+        for (slug, content) in notes {
+            let lines = content.lines()
+                .enumerate()
+                .filter(|(_, line)| line.to_lowercase().contains(&query))
+                .collect::<Vec<_>>();
+            if lines.is_empty() {
+                continue;
+            }
+            found += 1;
+            let title = match problems.iter().find(|ss| ss.stat.question_title_slug == slug) {
+                Some(ss) => format!("[{}] {}", ss.stat.frontend_question_id, ss.stat.question_title),
+                None => slug.clone(),
+            };
+            println!("{}", Style::new().bold().paint(title));
+            for (i, line) in lines {
+                println!("  {}: {}", Yellow.paint((i + 1).to_string()), line);
+            }
+        }
+        if found == 0 {
+            println!("no note matches {}", query);
+        }
+        Ok(())
+    }
+}
@@ -1,13 +1,21 @@
 use std::env;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use log::debug;
-use crate::leetcode::cache::DB_KEYS;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::seq::SliceRandom;
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
 use crate::leetcode::net::problems_all::ProblemsAll;
 use crate::leetcode::error::Result;
+use crate::leetcode::filter::Filter;
 use crate::leetcode::lang::Language;
-use crate::leetcode::net::question_data::QuestionData;
+use crate::leetcode::markdown;
+use crate::leetcode::config::CONST_CONFIG;
+use crate::leetcode::net::question_data::{QuestionData, TopicTag};
+use crate::leetcode::scaffold::{self, Scaffold};
+use crate::leetcode::template::{self, TemplateData};
 
 
 pub struct PickPlugin {
@@ -32,7 +40,28 @@ impl PickPlugin {
         Ok(())
     }
 
-    pub async fn fetch_question_data(&mut self, question_id: i32) -> Result<()> {
+    /// 随机选择一个满足`filter`的题目，`weighted`为true时按出现频率加权
+    pub fn random_question_id(&self, filter: &Filter, weighted: bool) -> Option<i32> {
+        let candidates = self.problems_all.as_ref()
+            .expect("fail to fetch problems")
+            .stat_status_pairs
+            .iter()
+            .filter(|ss| filter.matches(ss))
+            .collect::<Vec<_>>();
+        let mut rng = rand::thread_rng();
+        let picked = if weighted {
+            // 没有频率数据的题目也有机会被选中
+            let weights = candidates.iter().map(|ss| ss.frequency.max(0) as u64 + 1);
+            let index = WeightedIndex::new(weights).ok()?;
+            candidates[index.sample(&mut rng)]
+        } else {
+            *candidates.choose(&mut rng)?
+        };
+        println!("picked [{}] {}", picked.stat.frontend_question_id, picked.stat.question_title);
+        Some(picked.stat.question_id)
+    }
+
+    pub async fn fetch_question_data(&mut self, question_id: i32, refresh: bool) -> Result<()> {
         self.question_id = question_id;
         self.question_title_slug = self.problems_all.as_ref()
             .expect("fail to fetch problems")
@@ -45,35 +74,40 @@ impl PickPlugin {
             .stat
             .question_title_slug
             .clone();
-        self.question_data = Some(QuestionData::fetch(&self.question_title_slug).await?);
+        self.question_data = Some(if refresh {
+            QuestionData::refresh(&self.question_title_slug).await?
+        } else {
+            QuestionData::fetch(&self.question_title_slug).await?
+        });
         debug!("QuestionData: {}", serde_json::to_string_pretty(
                 self.question_data.as_ref().unwrap()
             ).unwrap());
         Ok(())
     }
 
-    pub async fn save_to_file(&mut self, language: Option<String>) -> Result<()> {
+    /// 文件已存在时按`on_conflict`处理，为None时询问；
+    /// `readme`为true时另外生成包含完整描述的markdown文件
+    ///
+    /// # Return
+    /// 生成的文件
+    pub async fn save_to_file(&mut self, language: Option<String>, on_conflict: Option<Conflict>, readme: bool) -> Result<PathBuf> {
         let question_data = self.question_data.as_ref().unwrap();
 
         let language = self.parse_language(language).await.unwrap_or(Language::C);
-        crate::leetcode::cache::set(DB_KEYS.language.to_string(), language.name.to_string()).await?;
+        crate::leetcode::cache::set(DB_TREES.config, DB_KEYS.language.to_string(), language.name.to_string()).await?;
 
-        let mut filename = env::current_dir()?;
-        filename.push(format!("{}-{}", self.question_id, self.question_title_slug));
+        let dir = env::current_dir()?;
+        let name = format!("{}-{}", self.question_id, self.question_title_slug);
+        let mut filename = dir.join(&name);
         filename.set_extension(language.extension);
 
-        let comment_content = if !question_data.translated_content.is_empty() {
-            wrap_content_with_comment(
-                &question_data.translated_content,
-                language.single_line_comment,
-            )
+        let content = if !question_data.translated_content.is_empty() {
+            &question_data.translated_content
         } else {
-            wrap_content_with_comment(
-                &question_data.content,
-                language.single_line_comment,
-            )
+            &question_data.content
         };
-        debug!("comment_content: {}", comment_content);
+        let description = markdown::html_to_markdown(content);
+        debug!("description: {}", description);
 
         let code_content = question_data.code_definition
             .iter()
@@ -84,23 +118,89 @@ impl PickPlugin {
             .default_code
             .as_str();
         debug!("code content: {}", code_content);
+        let scaffold = Scaffold::new(&language, question_data, code_content);
 
-        let mut file = File::create(filename)
-            .expect("create file failed");
+        let ss = self.problems_all.as_ref()
+            .unwrap()
+            .stat_status_pairs
+            .iter()
+            .find(|ss| ss.stat.question_id == self.question_id)
+            .unwrap();
+        let data = TemplateData {
+            id: self.question_id,
+            frontend_id: &ss.stat.frontend_question_id,
+            slug: &self.question_title_slug,
+            title: &ss.stat.question_title,
+            difficulty: ss.difficulty.name(),
+            tags: question_data.topic_tags.iter().map(TopicTag::display_name).collect(),
+            similar_questions: question_data.similar_questions()
+                .iter()
+                .map(|question| question.display_title().to_string())
+                .collect(),
+            hints: question_data.hints.iter().map(|hint| markdown::html_to_markdown(hint)).collect(),
+            url: format!("{}{}/", CONST_CONFIG.url.leetcode.problems, self.question_title_slug),
+            language: language.name,
+            comment: language.single_line_comment,
+            description: markdown::wrap(&description, 80),
+            code: scaffold.to_string(),
+            prelude: &scaffold.prelude,
+            solution: &scaffold.code,
+            main: &scaffold.main,
+            sample_tests: &question_data.sample_test_case,
+        };
+        let mut content = template::render(&language, &data)?;
+
+        if filename.exists() {
+            match on_conflict.unwrap_or_else(|| ask_conflict(&filename)) {
+                Conflict::Keep => {
+                    println!("keep {}", filename.display());
+                    return Ok(filename);
+                }
+                Conflict::Overwrite => {}
+                Conflict::Backup => {
+                    let backup = backup_file(&filename)?;
+                    println!("backup {} to {}", filename.display(), backup.display());
+                }
+                Conflict::Merge => {
+                    let old_content = fs::read_to_string(&filename)?;
+                    match scaffold::extract_code(&old_content)
+                        .and_then(|code| scaffold::replace_code(&content, &code)) {
+                        Some(merged) => content = merged,
+                        None => {
+                            println!("can not find @lc code markers, keep {}", filename.display());
+                            return Ok(filename);
+                        }
+                    }
+                }
+            }
+        }
+
+        // 选择保留时描述文件也不改动
+        if readme {
+            let readme_file = dir.join(format!("{}.md", name));
+            let readme_content = format!("# {}. {}\n\n{} | {}\n\n{}\n",
+                                         data.frontend_id, data.title, data.difficulty, data.url, description);
+            fs::write(&readme_file, readme_content)?;
+        }
 
-        file.write(comment_content.as_bytes())?;
-        file.write("\n\n".as_bytes())?;
-        file.write(code_content.as_bytes())?;
+        let mut file = File::create(&filename)
+            .expect("create file failed");
+        file.write_all(content.as_bytes())?;
         file.flush()?;
 
-        Ok(())
+        if language.name == Language::RUST.name {
+            let file_name = filename.file_name().unwrap().to_string_lossy();
+            scaffold::rust::register_bin(&dir, &name, &file_name)?;
+        }
+
+        Ok(filename)
     }
 
     async fn parse_language(&self, language: Option<String>) -> Result<Language<'static>> {
-        return match language {
+        match language {
             None => {
                 // 从Cache中读取
-                if let Some(s) = crate::leetcode::cache::get(DB_KEYS.language).await? {
+                if let Some(s) = crate::leetcode::cache::get(DB_TREES.config, DB_KEYS.language).await? {
                     Ok(Language::from_str(s.as_str())?)
                 } else {
                     Ok(Language::C)
@@ -109,19 +209,46 @@ impl PickPlugin {
             Some(s) => {
                 Ok(Language::from_str(&s)?)
             }
-        };
+        }
     }
 }
 
-fn wrap_content_with_comment(content: &str, comment: &str) -> String {
-    let content = html2text::from_read(content.as_bytes(), 80);
-    content.lines()
-        .map(|line| {
-            let mut new_line = comment.to_string();
-            new_line.push(' ');
-            new_line.push_str(line);
-            new_line
-        })
-        .collect::<Vec<String>>()
-        .join("\n")
+/// 要生成的文件已存在时的处理方式
+pub enum Conflict {
+    Keep,
+    Overwrite,
+    /// 先把已存在的文件重命名
+    Backup,
+    /// 更新题目描述等内容，保留标记之间的代码
+    Merge,
+}
+
+fn ask_conflict(filename: &Path) -> Conflict {
+    loop {
+        println!("{} already exists, [k]eep/[o]verwrite/[b]ackup/[m]erge? (default: keep)", filename.display());
+        let mut answer = String::new();
+        // 读不到输入时（例如EOF）保留原文件
+        if std::io::stdin().read_line(&mut answer).unwrap_or(0) == 0 {
+            return Conflict::Keep;
+        }
+        match answer.trim().to_lowercase().as_str() {
+            "" | "k" | "keep" => return Conflict::Keep,
+            "o" | "overwrite" => return Conflict::Overwrite,
+            "b" | "backup" => return Conflict::Backup,
+            "m" | "merge" => return Conflict::Merge,
+            _ => {}
+        }
+    }
+}
+
+/// 重命名为`<filename>.bak`，已存在时依次尝试`.bak1`、`.bak2`...
+fn backup_file(filename: &Path) -> Result<PathBuf> {
+    let mut backup = PathBuf::from(format!("{}.bak", filename.display()));
+    let mut i = 1;
+    while backup.exists() {
+        backup = PathBuf::from(format!("{}.bak{}", filename.display(), i));
+        i += 1;
+    }
+    fs::rename(filename, &backup)?;
+    Ok(backup)
 }
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use ansi_term::Color::{Green, Red, Yellow};
+use ansi_term::Style;
+use crate::leetcode::error::Result;
+use crate::leetcode::net::problems_all::{ProblemsAll, StatStatus};
+use crate::leetcode::net::study_plan::{PlanQuestion, StudyPlan, KNOWN_PLANS};
+use crate::leetcode::term::icon::Icon;
+use crate::leetcode::term::text;
+
+pub struct PlanPlugin {
+    problems_all: Option<ProblemsAll>,
+}
+
+impl PlanPlugin {
+    pub fn new() -> PlanPlugin {
+        PlanPlugin {
+            problems_all: None,
+        }
+    }
+
+    pub async fn fetch_problems_all(&mut self) -> Result<()> {
+        self.problems_all = Some(ProblemsAll::fetch().await?);
+        Ok(())
+    }
+
+    /// frontend_question_id -> StatStatus
+    fn stat_status_map(&self) -> HashMap<&str, &StatStatus> {
+        self.problems_all.as_ref()
+            .expect("fail to fetch problems")
+            .stat_status_pairs
+            .iter()
+            .map(|ss| (ss.stat.frontend_question_id.as_str(), ss))
+            .collect()
+    }
+
+    fn is_solved(map: &HashMap<&str, &StatStatus>, question: &PlanQuestion) -> bool {
+        map.get(question.question_frontend_id.as_str())
+            .is_some_and(|ss| ss.status.as_deref() == Some("ac"))
+    }
+
+    /// 列出常用的计划和已经缓存的计划，已缓存的显示进度
+    pub async fn list(&self) -> Result<()> {
+        let cached = StudyPlan::cached_slugs().await?;
+        let mut slugs = cached.clone();
+        for slug in KNOWN_PLANS {
+            if !slugs.iter().any(|cached| cached == slug) {
+                slugs.push(slug.to_string());
+            }
+        }
+        let map = self.stat_status_map();
+        let slug_width = slugs.iter().map(|slug| text::width(slug)).max().unwrap_or(0);
+        for slug in &slugs {
+            if cached.contains(slug) {
+                let plan = StudyPlan::fetch(slug, false).await?;
+                let total = plan.questions().count();
+                let solved = plan.questions().filter(|question| PlanPlugin::is_solved(&map, question)).count();
+                println!("{} {:>4}/{:<4} {}", text::pad(slug, slug_width), solved, total, plan.name);
+            } else {
+                println!("{} {}", text::pad(slug, slug_width), Style::new().dimmed().paint("not downloaded yet"));
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn show(&self, slug: &str, refresh: bool) -> Result<()> {
+        let plan = StudyPlan::fetch(slug, refresh).await?;
+        let map = self.stat_status_map();
+        let total = plan.questions().count();
+        let solved = plan.questions().filter(|question| PlanPlugin::is_solved(&map, question)).count();
+        let bold = Style::new().bold();
+        println!("{} ({}/{})", bold.paint(&plan.name), solved, total);
+        for group in &plan.plan_sub_groups {
+            println!();
+            println!("{}", bold.paint(&group.name));
+            for question in &group.questions {
+                let status = match map.get(question.question_frontend_id.as_str()).and_then(|ss| ss.status.as_deref()) {
+                    Some("ac") => Green.paint(Icon::Yes.to_string()).to_string(),
+                    Some(_) => Red.paint(Icon::No.to_string()).to_string(),
+                    None => Icon::Empty.to_string(),
+                };
+                let lock = if question.paid_only { Red.paint(Icon::Lock.to_string()).to_string() } else { String::new() };
+                let difficulty = match question.difficulty.to_lowercase().as_str() {
+                    "easy" => Green.paint("easy"),
+                    "medium" => Yellow.paint("medium"),
+                    _ => Red.paint("hard"),
+                };
+                println!("  {} [{}] {} {} {}",
+                         status, question.question_frontend_id, question.display_title(), difficulty, lock);
+            }
+        }
+        Ok(())
+    }
+
+    /// 按顺序找到第一道没有通过的题目，返回内部id用于`pick`，不在题目列表中的题目会被跳过
+    pub async fn next_question_id(&self, slug: &str) -> Result<Option<i32>> {
+        let plan = StudyPlan::fetch(slug, false).await?;
+        let map = self.stat_status_map();
+        let next = plan.questions()
+            .filter_map(|question| map.get(question.question_frontend_id.as_str()).map(|ss| (question, ss)))
+            .find(|(_, ss)| ss.status.as_deref() != Some("ac"));
+        match next {
+            Some((question, ss)) => {
+                println!("next in {}: [{}] {}", plan.name, question.question_frontend_id, question.display_title());
+                Ok(Some(ss.stat.question_id))
+            }
+            None => {
+                println!("all problems in {} are solved", plan.name);
+                Ok(None)
+            }
+        }
+    }
+}
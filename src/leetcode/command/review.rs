@@ -0,0 +1,93 @@
+use std::io::{self, IsTerminal};
+use ansi_term::Color::{Green, Red, Yellow};
+use ansi_term::Style;
+use chrono::Local;
+use crate::leetcode::error::Result;
+use crate::leetcode::net::problems_all::{ProblemsAll, StatStatus};
+use crate::leetcode::review::{self, ReviewItem, MAX_GRADE};
+
+pub struct ReviewPlugin {
+    problems_all: Option<ProblemsAll>,
+}
+
+impl ReviewPlugin {
+    pub fn new() -> ReviewPlugin {
+        ReviewPlugin {
+            problems_all: None,
+        }
+    }
+
+    pub async fn fetch_problems_all(&mut self) -> Result<()> {
+        self.problems_all = Some(ProblemsAll::fetch().await?);
+        Ok(())
+    }
+
+    fn stat_status(&self, question_id: i32) -> Option<&StatStatus> {
+        self.problems_all.as_ref()
+            .expect("fail to fetch problems")
+            .stat_status_pairs
+            .iter()
+            .find(|ss| ss.stat.question_id == question_id)
+    }
+
+    fn title(&self, item: &ReviewItem) -> String {
+        match self.stat_status(item.question_id) {
+            Some(ss) => format!("[{}] {}", ss.stat.frontend_question_id, ss.stat.question_title),
+            None => item.question_title_slug.clone(),
+        }
+    }
+
+    /// 列出今天到期（包括已经过期）的题目，返回第一道的id
+    pub async fn today(&self) -> Result<Option<i32>> {
+        let today = Local::now().date_naive();
+        let items = ReviewItem::load_all().await?;
+        let due = items.iter().filter(|item| item.is_due(today)).collect::<Vec<_>>();
+        if due.is_empty() {
+            match items.first() {
+                Some(item) => println!("nothing to review today, next: {} on {}", self.title(item), item.due),
+                None => println!("nothing to review, rate a problem with `review done <id> <grade>` or `submit --grade`"),
+            }
+            return Ok(None);
+        }
+        println!("{}", Style::new().bold().paint(format!("{} problem(s) to review", due.len())));
+        for item in &due {
+            let overdue = (today - item.due).num_days();
+            let when = if overdue > 0 {
+                Red.paint(format!("{} day(s) overdue", overdue))
+            } else {
+                Yellow.paint("today".to_string())
+            };
+            println!("  {} {} (reviewed {} time(s))", self.title(item), when, item.repetitions);
+        }
+        Ok(due.first().map(|item| item.question_id))
+    }
+
+    /// 按自评分数重新安排复习日期
+    pub async fn done(&self, question_id: i32, grade: u8) -> Result<()> {
+        let ss = self.stat_status(question_id).expect("question id invalid");
+        let item = review::record(question_id, &ss.stat.question_title_slug, grade, Local::now().date_naive()).await?;
+        println!("next review of {} on {}", self.title(&item), Green.paint(item.due.to_string()));
+        Ok(())
+    }
+}
+
+/// 通过后询问自评分数，不是终端或者输入为空时跳过
+pub fn ask_grade() -> Option<u8> {
+    if !io::stdin().is_terminal() {
+        return None;
+    }
+    loop {
+        println!("how well did you remember it? 0 (not at all) - {} (easily), empty to skip review", MAX_GRADE);
+        let mut answer = String::new();
+        if io::stdin().read_line(&mut answer).unwrap_or(0) == 0 {
+            return None;
+        }
+        match answer.trim() {
+            "" => return None,
+            answer => match answer.parse::<u8>() {
+                Ok(grade) if grade <= MAX_GRADE => return Some(grade),
+                _ => continue,
+            },
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use ansi_term::Style;
+use crate::leetcode::config::CONST_CONFIG;
+use crate::leetcode::error::Result;
+use crate::leetcode::markdown;
+use crate::leetcode::note;
+use crate::leetcode::net::problems_all::{ProblemsAll, StatStatus};
+use crate::leetcode::net::question_data::{QuestionData, TopicTag};
+
+pub struct ShowPlugin {
+    problems_all: Option<ProblemsAll>,
+    question_data: Option<QuestionData>,
+    question_id: i32,
+}
+
+impl ShowPlugin {
+    pub fn new() -> ShowPlugin {
+        ShowPlugin {
+            problems_all: None,
+            question_data: None,
+            question_id: 0,
+        }
+    }
+
+    pub async fn fetch_problems_all(&mut self) -> Result<()> {
+        self.problems_all = Some(ProblemsAll::fetch().await?);
+        Ok(())
+    }
+
+    pub async fn fetch_question_data(&mut self, question_id: i32, refresh: bool) -> Result<()> {
+        self.question_id = question_id;
+        let slug = &self.stat_status().stat.question_title_slug;
+        self.question_data = Some(if refresh {
+            QuestionData::refresh(slug).await?
+        } else {
+            QuestionData::fetch(slug).await?
+        });
+        Ok(())
+    }
+
+    fn stat_status(&self) -> &StatStatus {
+        self.problems_all.as_ref()
+            .expect("fail to fetch problems")
+            .stat_status_pairs
+            .iter()
+            .find(|ss| ss.stat.question_id == self.question_id)
+            .expect("question id invalid")
+    }
+
+    /// `hints`为false时只显示提示的数量
+    pub async fn show(&self, hints: bool) -> Result<()> {
+        let ss = self.stat_status();
+        let question_data = self.question_data.as_ref().unwrap();
+        let bold = Style::new().bold();
+
+        println!("{}", bold.paint(format!("[{}] {}", ss.stat.frontend_question_id, ss.stat.question_title)));
+        println!("{}{}/", CONST_CONFIG.url.leetcode.problems, ss.stat.question_title_slug);
+        println!();
+        println!("{} {}", bold.paint("difficulty:"), ss.difficulty);
+        println!("{} {} / {} ({})",
+                 bold.paint("accepted:"),
+                 question_data.stats.total_accepted,
+                 question_data.stats.total_submission,
+                 question_data.stats.ac_rate);
+        println!("{} {}  {} {}",
+                 bold.paint("likes:"), question_data.likes,
+                 bold.paint("dislikes:"), question_data.dislikes);
+        if !question_data.topic_tags.is_empty() {
+            let tags = question_data.topic_tags.iter()
+                .map(TopicTag::display_name)
+                .collect::<Vec<_>>();
+            println!("{} {}", bold.paint("tags:"), tags.join(", "));
+        }
+        let companies = question_data.company_tags();
+        if !companies.is_empty() {
+            let companies = companies.iter()
+                .map(|company| format!("{} ({})", company.name, company.times_encountered))
+                .collect::<Vec<_>>();
+            println!("{} {}", bold.paint("companies:"), companies.join(", "));
+        }
+        let similar_questions = question_data.similar_questions();
+        if !similar_questions.is_empty() {
+            println!("{}", bold.paint("similar questions:"));
+            for question in similar_questions {
+                println!("  - {} [{}] {}{}/",
+                         question.display_title(),
+                         question.difficulty.to_lowercase(),
+                         CONST_CONFIG.url.leetcode.problems,
+                         question.title_slug);
+            }
+        }
+        if !question_data.hints.is_empty() {
+            if hints {
+                println!("{}", bold.paint("hints:"));
+                for (i, hint) in question_data.hints.iter().enumerate() {
+                    println!("  {}. {}", i + 1, markdown::html_to_markdown(hint));
+                }
+            } else {
+                println!("{} {} (use --hints to show)", bold.paint("hints:"), question_data.hints.len());
+            }
+        }
+
+        let content = if !question_data.translated_content.is_empty() {
+            &question_data.translated_content
+        } else {
+            &question_data.content
+        };
+        println!();
+        println!("{}", markdown::wrap(&markdown::html_to_markdown(content), 80));
+
+        if let Some(note) = note::load(&ss.stat.question_title_slug).await? {
+            println!();
+            println!("{}", bold.paint("note:"));
+            println!("{}", note.trim_end());
+        }
+        Ok(())
+    }
+}
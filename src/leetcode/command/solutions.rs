@@ -0,0 +1,137 @@
+use ansi_term::Color::Yellow;
+use ansi_term::Style;
+use anyhow::anyhow;
+use crate::leetcode::error::{LeetcodeError, Result};
+use crate::leetcode::markdown;
+use crate::leetcode::net::problems_all::{ProblemsAll, StatStatus};
+use crate::leetcode::net::solution::SolutionSummary;
+
+pub struct SolutionsPlugin {
+    problems_all: Option<ProblemsAll>,
+}
+
+/// 统一代码块语言的写法，例如`C++`和`cpp`、`Python3`和`python`
+fn normalize_lang(lang: &str) -> String {
+    let lang = lang.trim().to_lowercase();
+    match lang.as_str() {
+        "c++" | "cpp" | "cc" => "cpp",
+        "python" | "python3" | "py" => "python",
+        "golang" | "go" => "go",
+        "rust" | "rs" => "rust",
+        "javascript" | "js" => "javascript",
+        "typescript" | "ts" => "typescript",
+        "c#" | "csharp" | "cs" => "csharp",
+        lang => lang,
+    }.to_string()
+}
+
+/// 去掉语言不是`lang`的代码块，没有标明语言的代码块保留
+fn filter_code_blocks(content: &str, lang: &str) -> String {
+    let lang = normalize_lang(lang);
+    let mut lines = Vec::new();
+    // Some(true)表示在保留的代码块中
+    let mut in_block: Option<bool> = None;
+    for line in content.lines() {
+        let fence = line.trim_start().starts_with("```");
+        match in_block {
+            None if fence => {
+                // 形如```Python [sol1-Python3]
+                let info = line.trim_start().trim_start_matches('`');
+                let keep = match info.split_whitespace().next() {
+                    Some(block_lang) => normalize_lang(block_lang) == lang,
+                    None => true,
+                };
+                in_block = Some(keep);
+                if keep {
+                    lines.push(line);
+                }
+            }
+            None => lines.push(line),
+            Some(keep) => {
+                if keep {
+                    lines.push(line);
+                }
+                if fence {
+                    in_block = None;
+                }
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+impl SolutionsPlugin {
+    pub fn new() -> SolutionsPlugin {
+        SolutionsPlugin {
+            problems_all: None,
+        }
+    }
+
+    pub async fn fetch_problems_all(&mut self) -> Result<()> {
+        self.problems_all = Some(ProblemsAll::fetch().await?);
+        Ok(())
+    }
+
+    fn stat_status(&self, question_id: i32) -> &StatStatus {
+        self.problems_all.as_ref()
+            .expect("fail to fetch problems")
+            .stat_status_pairs
+            .iter()
+            .find(|ss| ss.stat.question_id == question_id)
+            .expect("question id invalid")
+    }
+
+    pub async fn list(&self, question_id: i32, limit: usize) -> Result<()> {
+        let ss = self.stat_status(question_id);
+        let solutions = SolutionSummary::fetch_list(&ss.stat.question_title_slug, limit).await?;
+        if solutions.is_empty() {
+            println!("no solution for [{}] {}", ss.stat.frontend_question_id, ss.stat.question_title);
+            return Ok(());
+        }
+        println!("{}", Style::new().bold().paint(format!(
+            "solutions of [{}] {} ({} in total)",
+            ss.stat.frontend_question_id, ss.stat.question_title, ss.stat.total_column_articles)));
+        for (i, solution) in solutions.iter().enumerate() {
+            println!("{:3}. {} {} {}",
+                     i + 1,
+                     solution.title,
+                     Style::new().dimmed().paint(format!("by {}", solution.author)),
+                     Yellow.paint(format!("▲{}", solution.votes)));
+        }
+        Ok(())
+    }
+
+    /// 显示第`n`篇题解（从1开始），`lang`不为None时只保留该语言的代码块
+    pub async fn show(&self, question_id: i32, n: usize, lang: Option<&str>) -> Result<()> {
+        let ss = self.stat_status(question_id);
+        let solutions = SolutionSummary::fetch_list(&ss.stat.question_title_slug, n).await?;
+        let solution = n.checked_sub(1)
+            .and_then(|i| solutions.get(i))
+            .ok_or_else(|| LeetcodeError::Any(anyhow!("there are only {} solution(s)", solutions.len())))?;
+        let mut content = solution.fetch_content().await?;
+        // 个别题解是html
+        if content.trim_start().starts_with('<') {
+            content = markdown::html_to_markdown(&content);
+        }
+        if let Some(lang) = lang {
+            content = filter_code_blocks(&content, lang);
+        }
+        println!("{}", Style::new().bold().paint(&solution.title));
+        println!("{}", Style::new().dimmed().paint(format!("by {}  ▲{}", solution.author, solution.votes)));
+        println!();
+        println!("{}", markdown::wrap(&content, 80));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::filter_code_blocks;
+
+    #[test]
+    fn keep_only_selected_language() {
+        let content = "## 思路\n\n```Python [sol1-Python3]\nprint(1)\n```\n\n```C++ [sol1-C++]\nint a;\n```\n\n```\nplain\n```\n结束";
+        assert_eq!(filter_code_blocks(content, "cpp"), "## 思路\n\n\n```C++ [sol1-C++]\nint a;\n```\n\n```\nplain\n```\n结束");
+        assert_eq!(filter_code_blocks(content, "python3"), "## 思路\n\n```Python [sol1-Python3]\nprint(1)\n```\n\n\n```\nplain\n```\n结束");
+    }
+}
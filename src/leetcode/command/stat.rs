@@ -0,0 +1,193 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use ansi_term::Color::{Fixed, Green, Red, Yellow};
+use ansi_term::Style;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use crate::leetcode::error::Result;
+use crate::leetcode::net::problems_all::ProblemsAll;
+use crate::leetcode::net::tag_index::TagIndex;
+use crate::leetcode::record::{self, SubmissionRecord};
+use crate::leetcode::term::text;
+
+const BAR_WIDTH: usize = 30;
+/// 最多显示的标签数量
+const MAX_TAGS: usize = 20;
+/// 通过率历史显示的月数
+const HISTORY_MONTHS: usize = 12;
+/// 热力图从少到多的颜色
+const HEAT_COLORS: [u8; 4] = [22, 28, 34, 40];
+
+pub struct StatPlugin {
+    problems_all: Option<ProblemsAll>,
+    records: Vec<SubmissionRecord>,
+    tag_index: TagIndex,
+}
+
+/// 形如`█████░░░░░`的进度条
+fn bar(done: usize, total: usize, width: usize) -> String {
+    let filled = (done * width + total / 2).checked_div(total).unwrap_or(0).min(width);
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+fn percent(done: usize, total: usize) -> f64 {
+    if total == 0 { 0.0 } else { done as f64 * 100.0 / total as f64 }
+}
+
+impl StatPlugin {
+    pub fn new() -> StatPlugin {
+        StatPlugin {
+            problems_all: None,
+            records: Vec::new(),
+            tag_index: TagIndex::default(),
+        }
+    }
+
+    pub async fn fetch(&mut self) -> Result<()> {
+        self.problems_all = Some(ProblemsAll::fetch().await?);
+        self.records = SubmissionRecord::load_all().await?;
+        self.tag_index = TagIndex::load().await?;
+        Ok(())
+    }
+
+    /// `weeks`为热力图显示的周数
+    pub fn show(&self, weeks: usize) {
+        let bold = Style::new().bold();
+        println!("{}", bold.paint("progress"));
+        self.show_difficulties();
+        println!();
+        println!("{}", bold.paint("tags"));
+        self.show_tags();
+        println!();
+        println!("{}", bold.paint("submissions"));
+        self.show_calendar(weeks);
+        println!();
+        println!("{}", bold.paint("acceptance"));
+        self.show_history();
+    }
+
+    fn show_difficulties(&self) {
+        let problems_all = self.problems_all.as_ref().expect("fail to fetch problems");
+        let mut totals = [0usize; 3];
+        for ss in &problems_all.stat_status_pairs {
+            if let Some(total) = totals.get_mut(ss.difficulty.level as usize - 1) {
+                *total += 1;
+            }
+        }
+        let rows = [
+            ("all", problems_all.num_solved as usize, problems_all.num_total as usize, Style::new()),
+            ("easy", problems_all.ac_easy as usize, totals[0], Green.normal()),
+            ("medium", problems_all.ac_medium as usize, totals[1], Yellow.normal()),
+            ("hard", problems_all.ac_hard as usize, totals[2], Red.normal()),
+        ];
+        for (name, solved, total, style) in rows {
+            println!("  {} {} {:>5}/{:<5} {:5.1}%",
+                     text::pad(name, 6),
+                     style.paint(bar(solved, total, BAR_WIDTH)),
+                     solved, total, percent(solved, total));
+        }
+    }
+
+    /// 只统计已经建立索引的标签
+    fn show_tags(&self) {
+        if self.tag_index.tags.is_empty() {
+            println!("  no tag indexed yet, run `list --tag <tag>` or `cache prefetch --tag <tag>` first");
+            return;
+        }
+        let solved = self.problems_all.as_ref()
+            .expect("fail to fetch problems")
+            .stat_status_pairs
+            .iter()
+            .filter(|ss| ss.status.as_deref() == Some("ac"))
+            .map(|ss| ss.stat.frontend_question_id.as_str())
+            .collect::<HashSet<_>>();
+        let mut tags = self.tag_index.tags.iter()
+            .map(|(tag, ids)| {
+                let done = ids.iter().filter(|id| solved.contains(id.as_str())).count();
+                (tag, done, ids.len())
+            })
+            .collect::<Vec<_>>();
+        tags.sort_by(|a, b| percent(b.1, b.2).total_cmp(&percent(a.1, a.2)).then(b.2.cmp(&a.2)));
+        let name_width = tags.iter().map(|(tag, _, _)| text::width(tag)).max().unwrap_or(0).min(24);
+        for (tag, done, total) in tags.into_iter().take(MAX_TAGS) {
+            println!("  {} {} {:>5}/{:<5} {:5.1}%",
+                     text::fit(tag, name_width),
+                     Green.paint(bar(done, total, BAR_WIDTH)),
+                     done, total, percent(done, total));
+        }
+    }
+
+    /// 类似github的提交日历，每列是一周，颜色越深提交越多
+    fn show_calendar(&self, weeks: usize) {
+        let weeks = weeks.max(1);
+        let today = Local::now().date_naive();
+        let start = today
+            - Duration::days(today.weekday().num_days_from_monday() as i64)
+            - Duration::weeks(weeks as i64 - 1);
+        let mut counts: HashMap<NaiveDate, usize> = HashMap::new();
+        for record in &self.records {
+            *counts.entry(record.date()).or_default() += 1;
+        }
+
+        // 月份标签写在该月第一个周一的上方
+        let mut months = vec![' '; weeks * 2];
+        for week in 0..weeks {
+            let monday = start + Duration::weeks(week as i64);
+            if monday.day() <= 7 && week * 2 + 3 <= months.len() {
+                for (i, c) in monday.format("%b").to_string().chars().enumerate() {
+                    months[week * 2 + i] = c;
+                }
+            }
+        }
+        println!("      {}", months.iter().collect::<String>().trim_end());
+
+        let dim = Style::new().dimmed();
+        for (weekday, label) in ["Mon", "", "Wed", "", "Fri", "", "Sun"].iter().enumerate() {
+            let mut line = format!("  {} ", text::pad(label, 3));
+            for week in 0..weeks {
+                let day = start + Duration::days((week * 7 + weekday) as i64);
+                let cell = match counts.get(&day) {
+                    _ if day > today => "  ".to_string(),
+                    None => dim.paint("· ").to_string(),
+                    Some(&count) => {
+                        let level = match count {
+                            1 => 0,
+                            2..=3 => 1,
+                            4..=6 => 2,
+                            _ => 3,
+                        };
+                        Fixed(HEAT_COLORS[level]).paint("■ ").to_string()
+                    }
+                };
+                line.push_str(&cell);
+            }
+            println!("{}", line);
+        }
+        let active_days = counts.keys().filter(|&&day| day >= start).count();
+        println!("  {} submission(s) on {} day(s), streak: {} day(s)",
+                 self.records.iter().filter(|record| record.date() >= start).count(),
+                 active_days,
+                 record::streak(&self.records, today));
+    }
+
+    /// 按月统计通过率
+    fn show_history(&self) {
+        if self.records.is_empty() {
+            println!("  no submission yet");
+            return;
+        }
+        let mut months: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+        for record in &self.records {
+            let entry = months.entry(record.date().format("%Y-%m").to_string()).or_default();
+            entry.1 += 1;
+            if record.is_accepted() {
+                entry.0 += 1;
+            }
+        }
+        let skip = months.len().saturating_sub(HISTORY_MONTHS);
+        for (month, (accepted, total)) in months.into_iter().skip(skip) {
+            println!("  {} {} {:>5}/{:<5} {:5.1}%",
+                     month,
+                     Green.paint(bar(accepted, total, BAR_WIDTH)),
+                     accepted, total, percent(accepted, total));
+        }
+    }
+}
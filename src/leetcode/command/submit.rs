@@ -4,12 +4,22 @@ use crate::leetcode::net::submit::{submit, SubmitArgs};
 use std::fs::File;
 use std::io::Read;
 use anyhow::anyhow;
+use leetcode_cache::batch::WriteBatch;
 use log::debug;
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
 use crate::leetcode::net::judge::JudgeResult;
+use crate::leetcode::net::problems_all::ProblemsAll;
+use crate::leetcode::record::SubmissionRecord;
+use crate::leetcode::review;
+use crate::leetcode::command::review::ask_grade;
+use crate::leetcode::scaffold;
 
 pub struct SubmitPlugin {
     submission_id: String,
     language: Option<Language<'static>>,
+    question_id: i32,
+    question_title_slug: String,
+    test_mode: bool,
 }
 
 impl SubmitPlugin {
@@ -17,6 +27,9 @@ impl SubmitPlugin {
         SubmitPlugin {
             submission_id: String::new(),
             language: None,
+            question_id: 0,
+            question_title_slug: String::new(),
+            test_mode: false,
         }
     }
 
@@ -25,6 +38,10 @@ impl SubmitPlugin {
         File::open(filename)
             .expect("fail to open file")
             .read_to_string(&mut content)?;
+        // pick生成的文件只提交标记之间的代码
+        if let Some(code) = scaffold::extract_code(&content) {
+            return Ok(code);
+        }
         content = content.lines()
             .filter(|l| {
                 !l.starts_with(self.language.as_ref().unwrap().single_line_comment)
@@ -39,8 +56,11 @@ impl SubmitPlugin {
 
     pub async fn submit_code(&mut self, filename: &str, test_data: Option<String>) -> Result<()> {
         if let Ok((id, slug, ext)) = sscanf::scanf!(filename, "{usize}-{str}.{str}") {
-            self.language = Some(Language::from_extension(&ext)
+            self.language = Some(Language::from_extension(ext)
                 .expect("extension not support"));
+            self.question_id = id as i32;
+            self.question_title_slug = slug.to_string();
+            self.test_mode = test_data.is_some();
 
             let typed_code = self
                 .read_code_from_file(filename)
@@ -77,11 +97,43 @@ impl SubmitPlugin {
         }
     }
 
-    pub async fn show_judge_result(&self) -> Result<()> {
-        JudgeResult::get(self.submission_id.as_str())
+    /// 通过后按`grade`安排复习，没有指定时询问
+    pub async fn show_judge_result(&self, grade: Option<u8>) -> Result<()> {
+        let judge_result = JudgeResult::get(self.submission_id.as_str())
             .await
-            .expect("get judge result failed")
-            .pretty_print();
+            .expect("get judge result failed");
+        judge_result.pretty_print();
+        if !self.test_mode {
+            self.record_submission(&judge_result).await?;
+            if judge_result.status_code == 10 {
+                if let Some(grade) = grade.or_else(ask_grade) {
+                    let item = review::record(self.question_id,
+                                              &self.question_title_slug,
+                                              grade,
+                                              chrono::Local::now().date_naive()).await?;
+                    println!("next review on {}", item.due);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 提交记录和题目状态一起写入，避免只更新了一半
+    async fn record_submission(&self, judge_result: &JudgeResult) -> Result<()> {
+        let record = SubmissionRecord::new(self.question_id,
+                                           self.question_title_slug.clone(),
+                                           judge_result);
+        let mut problems_all = ProblemsAll::fetch().await?;
+        problems_all.update_status(record.question_id, record.is_accepted());
+
+        let mut batch = WriteBatch::new();
+        batch.set_in(DB_TREES.problems,
+                     DB_KEYS.problems_all.to_string(),
+                     serde_json::to_string(&problems_all)?)
+            .set_in(DB_TREES.submissions,
+                    record.key(),
+                    serde_json::to_string(&record)?);
+        crate::leetcode::cache::write(batch).await?;
         Ok(())
     }
 }
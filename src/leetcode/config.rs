@@ -4,11 +4,13 @@ pub struct ConstConfig<'a> {
     pub url: Urls<'a>,
 }
 
+#[allow(dead_code)]
 pub struct Urls<'a> {
     pub leetcode: LeetcodeUrls<'a>,
     pub github: GithubUrls<'a>,
 }
 
+#[allow(dead_code)]
 pub struct LeetcodeUrls<'a> {
     pub base: &'a str,
     pub api: &'a str,
@@ -23,12 +25,16 @@ pub struct LeetcodeUrls<'a> {
     pub github_login: &'a str,
 }
 
+#[allow(dead_code)]
 pub struct GithubUrls<'a> {
     pub login: &'a str,
     pub session: &'a str,
 }
 
-pub const BASE: &'static str = "https://leetcode.cn";
+/// 缓存中按站点区分数据
+pub const SITE: &str = "leetcode.cn";
+
+pub const BASE: &str = formatcp!("https://{}", SITE);
 
 pub const CONST_CONFIG: ConstConfig<'static> = ConstConfig {
     url: Urls {
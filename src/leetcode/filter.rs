@@ -0,0 +1,73 @@
+//! 按条件过滤题目，`list`等命令共用
+
+use std::collections::HashSet;
+use anyhow::anyhow;
+use crate::leetcode::cli::FilterArgs;
+use crate::leetcode::error::{LeetcodeError, Result};
+use crate::leetcode::net::problems_all::StatStatus;
+use crate::leetcode::net::tag_index::TagIndex;
+
+pub struct Filter {
+    keyword: Option<String>,
+    /// 带有指定标签的题目的frontend_question_id
+    tagged: Option<HashSet<String>>,
+    difficulty: Option<i32>,
+    star: Option<bool>,
+    lock: Option<bool>,
+    finish: Option<bool>,
+}
+
+impl Filter {
+    pub async fn new(args: &FilterArgs) -> Result<Filter> {
+        let tagged = match &args.tag {
+            Some(tag) => Some(TagIndex::fetch(tag).await?),
+            None => None,
+        };
+        let difficulty = match &args.difficulty {
+            Some(difficulty) => Some(parse_difficulty(difficulty)?),
+            None => None,
+        };
+        Ok(Filter {
+            keyword: args.keyword.as_ref().map(|keyword| keyword.to_lowercase()),
+            tagged,
+            difficulty,
+            star: args.star,
+            lock: args.lock,
+            finish: args.finish,
+        })
+    }
+
+    pub fn matches(&self, ss: &StatStatus) -> bool {
+        if let Some(keyword) = &self.keyword {
+            let matched = ss.stat.frontend_question_id == *keyword
+                || ss.stat.question_title.to_lowercase().contains(keyword)
+                || ss.stat.question_title_slug.contains(keyword);
+            if !matched {
+                return false;
+            }
+        }
+        if let Some(tagged) = &self.tagged {
+            if !tagged.contains(&ss.stat.frontend_question_id) {
+                return false;
+            }
+        }
+        if let Some(difficulty) = self.difficulty {
+            if ss.difficulty.level != difficulty {
+                return false;
+            }
+        }
+        self.star.map_or(true, |star| ss.is_favor == star)
+            && self.lock.map_or(true, |lock| ss.paid_only == lock)
+            && self.finish.map_or(true, |finish| (ss.status.as_deref() == Some("ac")) == finish)
+    }
+}
+
+/// `easy`/`medium`/`hard`，也可以只写首字母
+fn parse_difficulty(difficulty: &str) -> Result<i32> {
+    match difficulty.to_lowercase().as_str() {
+        "easy" | "e" => Ok(1),
+        "medium" | "m" => Ok(2),
+        "hard" | "h" => Ok(3),
+        _ => Err(LeetcodeError::Any(anyhow!("unknown difficulty: {}", difficulty))),
+    }
+}
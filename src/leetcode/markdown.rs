@@ -0,0 +1,302 @@
+//! 把leetcode的题目描述（html）转换为markdown
+//!
+//! 相比`html2text`：保留上标下标（`10<sup>5</sup>`转换为`10^5`），
+//! `<pre>`中的示例原样放在代码块中，图片转换为链接
+
+use ego_tree::NodeRef;
+use scraper::{Html, Node};
+
+pub fn html_to_markdown(html: &str) -> String {
+    let fragment = Html::parse_fragment(html);
+    let mut converter = Converter::default();
+    converter.children(*fragment.root_element());
+    normalize(&converter.out)
+}
+
+/// 按`width`折行，代码块和图片所在的行保持不变
+pub fn wrap(markdown: &str, width: usize) -> String {
+    let mut in_fence = false;
+    let mut lines = Vec::new();
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_fence = !in_fence;
+        }
+        if in_fence || line.starts_with("```") || line.trim_start().starts_with("![") {
+            lines.push(line.to_string());
+            continue;
+        }
+        let indent = " ".repeat(hanging_indent(line));
+        let options = textwrap::Options::new(width).subsequent_indent(&indent);
+        lines.extend(textwrap::wrap(line, options).into_iter().map(|line| line.to_string()));
+    }
+    lines.join("\n")
+}
+
+/// 列表项（`- `或`1. `开头）折行后与内容对齐，其它行与行首的空格对齐
+fn hanging_indent(line: &str) -> usize {
+    let rest = line.trim_start_matches(' ');
+    let spaces = line.len() - rest.len();
+    let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let marker = if rest.starts_with("- ") {
+        2
+    } else if digits > 0 && rest[digits..].starts_with(". ") {
+        digits + 2
+    } else {
+        0
+    };
+    spaces + marker
+}
+
+#[derive(Default)]
+struct Converter {
+    out: String,
+    in_pre: bool,
+    /// 每一层列表的下一个序号，无序列表为`None`
+    lists: Vec<Option<usize>>,
+}
+
+impl Converter {
+    fn children(&mut self, node: NodeRef<Node>) {
+        for child in node.children() {
+            self.node(child);
+        }
+    }
+
+    fn node(&mut self, node: NodeRef<Node>) {
+        match node.value() {
+            Node::Text(text) => self.text(text),
+            Node::Element(element) => {
+                let name = element.name();
+                match name {
+                    "p" | "div" if !self.lists.is_empty() => {
+                        self.children(node);
+                    }
+                    "p" | "div" | "blockquote" => {
+                        self.block();
+                        self.children(node);
+                        self.block();
+                    }
+                    "br" => self.out.push('\n'),
+                    "strong" | "b" => self.inline(node, "**", "**"),
+                    "em" | "i" => self.inline(node, "*", "*"),
+                    "code" if !self.in_pre => self.inline(node, "`", "`"),
+                    "sup" => self.script(node, '^'),
+                    "sub" => self.script(node, '_'),
+                    "pre" => self.pre(node),
+                    "img" => {
+                        let src = element.attr("src").unwrap_or_default();
+                        let alt = element.attr("alt").unwrap_or_default();
+                        self.line();
+                        self.out.push_str(&format!("![{}]({})", alt.trim(), src.trim()));
+                        self.line();
+                    }
+                    "a" => match element.attr("href") {
+                        Some(href) if !self.in_pre => {
+                            let text = self.render(node);
+                            self.out.push_str(&format!("[{}]({})", text.trim(), href));
+                        }
+                        _ => self.children(node),
+                    },
+                    "ul" | "ol" => {
+                        if self.lists.is_empty() {
+                            self.block();
+                        }
+                        self.lists.push(if name == "ol" { Some(1) } else { None });
+                        self.children(node);
+                        self.lists.pop();
+                        if self.lists.is_empty() {
+                            self.block();
+                        }
+                    }
+                    "li" => self.list_item(node),
+                    "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                        let level = name[1..].parse::<usize>().unwrap_or(1);
+                        self.block();
+                        self.out.push_str(&format!("{} ", "#".repeat(level)));
+                        self.children(node);
+                        self.block();
+                    }
+                    "tr" => {
+                        self.line();
+                        self.children(node);
+                        self.line();
+                    }
+                    "td" | "th" => {
+                        if !self.out.ends_with('\n') {
+                            self.out.push_str(" | ");
+                        }
+                        self.children(node);
+                    }
+                    _ => self.children(node),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.in_pre {
+            self.out.push_str(&text.replace('\u{a0}', " "));
+            return;
+        }
+        // 连续的空白合并为一个空格，行首不需要空格
+        let mut collapsed = String::with_capacity(text.len());
+        for c in text.chars() {
+            if c.is_whitespace() {
+                let last = collapsed.chars().last().or_else(|| self.out.chars().last());
+                if !matches!(last, None | Some(' ') | Some('\n')) {
+                    collapsed.push(' ');
+                }
+            } else {
+                collapsed.push(c);
+            }
+        }
+        self.out.push_str(&collapsed);
+    }
+
+    /// 渲染子节点但不写入输出
+    fn render(&mut self, node: NodeRef<Node>) -> String {
+        let out = std::mem::take(&mut self.out);
+        self.children(node);
+        std::mem::replace(&mut self.out, out)
+    }
+
+    fn inline(&mut self, node: NodeRef<Node>, open: &str, close: &str) {
+        let text = self.render(node);
+        let trimmed = text.trim();
+        if trimmed.is_empty() || self.in_pre {
+            self.out.push_str(&text);
+            return;
+        }
+        // 标记需要紧贴内容，空白放到标记外面
+        if text.starts_with(' ') && !self.out.ends_with(' ') {
+            self.out.push(' ');
+        }
+        self.out.push_str(&format!("{}{}{}", open, trimmed, close));
+        if text.ends_with(' ') {
+            self.out.push(' ');
+        }
+    }
+
+    /// 上标和下标，例如`10^5`、`2^(n-1)`、`a_i`
+    fn script(&mut self, node: NodeRef<Node>, mark: char) {
+        let text = self.render(node);
+        let text = text.trim();
+        if text.chars().all(char::is_alphanumeric) {
+            self.out.push_str(&format!("{}{}", mark, text));
+        } else {
+            self.out.push_str(&format!("{}({})", mark, text));
+        }
+    }
+
+    fn pre(&mut self, node: NodeRef<Node>) {
+        self.block();
+        self.in_pre = true;
+        let text = self.render(node);
+        self.in_pre = false;
+        self.out.push_str("```\n");
+        self.out.push_str(text.trim_matches('\n').trim_end());
+        self.out.push_str("\n```");
+        self.block();
+    }
+
+    fn list_item(&mut self, node: NodeRef<Node>) {
+        self.line();
+        let depth = self.lists.len().max(1);
+        let marker = match self.lists.last_mut() {
+            Some(Some(n)) => {
+                *n += 1;
+                format!("{}. ", *n - 1)
+            }
+            _ => "- ".to_string(),
+        };
+        self.out.push_str(&"  ".repeat(depth - 1));
+        self.out.push_str(&marker);
+        self.children(node);
+        self.line();
+    }
+
+    /// 开始新的一行
+    fn line(&mut self) {
+        trim_end_spaces(&mut self.out);
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    /// 开始新的段落
+    fn block(&mut self) {
+        self.line();
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+}
+
+fn trim_end_spaces(s: &mut String) {
+    let len = s.trim_end_matches(' ').len();
+    s.truncate(len);
+}
+
+/// 去掉行尾空白，合并多余的空行
+fn normalize(markdown: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    for line in markdown.lines().map(str::trim_end) {
+        if line.is_empty() && lines.last().map_or(true, |last| last.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+    while lines.last().is_some_and(|last| last.is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{html_to_markdown, wrap};
+
+    #[test]
+    fn superscript_and_subscript() {
+        assert_eq!(html_to_markdown("<p><code>1 &lt;= n &lt;= 10<sup>5</sup></code></p>"), "`1 <= n <= 10^5`");
+        assert_eq!(html_to_markdown("<p>2<sup>n - 1</sup> and nums<sub>i</sub></p>"), "2^(n - 1) and nums_i");
+    }
+
+    #[test]
+    fn pre_becomes_code_block() {
+        let html = "<p><strong>示例 1：</strong></p>\n<pre><strong>输入：</strong>nums = [2,7]\n<strong>输出：</strong>[0,1]\n</pre>";
+        assert_eq!(html_to_markdown(html), "**示例 1：**\n\n```\n输入：nums = [2,7]\n输出：[0,1]\n```");
+    }
+
+    #[test]
+    fn image_becomes_link() {
+        let html = "<p>如图：</p><img alt=\" tree \" src=\"https://assets.leetcode.com/tree.jpg\" style=\"width: 300px;\" />";
+        assert_eq!(html_to_markdown(html), "如图：\n\n![tree](https://assets.leetcode.com/tree.jpg)");
+    }
+
+    #[test]
+    fn nested_lists() {
+        let html = "<ol><li>first<ul><li>inner a</li><li>inner b</li></ul></li><li><p>second</p></li></ol>";
+        assert_eq!(html_to_markdown(html), "1. first\n  - inner a\n  - inner b\n2. second");
+    }
+
+    #[test]
+    fn wrap_aligns_list_items() {
+        assert_eq!(wrap("- aaa bbb ccc", 9), "- aaa bbb\n  ccc");
+        assert_eq!(wrap("10. aaa bbb ccc", 11), "10. aaa bbb\n    ccc");
+        assert_eq!(wrap("  - aaa bbb ccc", 11), "  - aaa bbb\n    ccc");
+    }
+
+    #[test]
+    fn wrap_plain_line_without_hanging_indent() {
+        assert_eq!(wrap("10^5 is the upper bound", 12), "10^5 is the\nupper bound");
+        assert_eq!(wrap("-1 means no answer", 10), "-1 means\nno answer");
+    }
+
+    #[test]
+    fn wrap_keeps_code_blocks_and_images() {
+        let markdown = "```\nlong long long line\n```\n![a very long alt](https://leetcode.cn/a.png)";
+        assert_eq!(wrap(markdown, 8), markdown);
+    }
+}
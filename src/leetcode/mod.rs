@@ -1,10 +1,18 @@
 mod cache;
 mod command;
+mod browser;
 
 pub mod error;
 pub mod config;
 pub mod net;
 pub mod term;
 pub mod lang;
+pub mod record;
+pub mod note;
+pub mod review;
+pub mod scaffold;
+pub mod template;
+pub mod markdown;
+pub mod filter;
 pub mod app;
 pub mod cli;
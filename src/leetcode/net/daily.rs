@@ -0,0 +1,102 @@
+//! 每日一题，leetcode.cn使用`todayRecord`，leetcode.com使用`activeDailyCodingChallengeQuestion`
+
+use log::debug;
+use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::{Serialize, Deserialize};
+use serde_json::{json, Value};
+use anyhow::anyhow;
+use crate::leetcode::config::{CONST_CONFIG, SITE};
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
+
+use crate::leetcode::error::{LeetcodeError, Result};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyQuestion {
+    pub date: String,
+    pub question: Question,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Question {
+    pub question_id: String,
+    pub question_frontend_id: String,
+    pub title: String,
+    pub title_slug: String,
+    pub difficulty: String,
+    pub translated_title: Option<String>,
+}
+
+impl DailyQuestion {
+    pub async fn fetch() -> Result<DailyQuestion> {
+        let cookie = crate::leetcode::cache::get(DB_TREES.auth, DB_KEYS.cookie).await?.unwrap_or("".to_string());
+        let mut headers = HeaderMap::new();
+        headers.insert("Cookie", HeaderValue::from_str(&cookie).unwrap());
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()?;
+        let (field, query) = if SITE.ends_with(".cn") {
+            ("todayRecord", r#"
+                query questionOfToday {
+                  todayRecord {
+                    date
+                    question {
+                      questionId
+                      questionFrontendId
+                      title
+                      titleSlug
+                      difficulty
+                      translatedTitle
+                    }
+                  }
+                }
+            "#)
+        } else {
+            ("activeDailyCodingChallengeQuestion", r#"
+                query questionOfToday {
+                  activeDailyCodingChallengeQuestion {
+                    date
+                    question {
+                      questionId
+                      questionFrontendId
+                      title
+                      titleSlug
+                      difficulty
+                    }
+                  }
+                }
+            "#)
+        };
+        let j = json!({
+            "query": query,
+            "variables": json!({}),
+            "operationName": "questionOfToday"
+        });
+        let res = client.post(CONST_CONFIG.url.leetcode.graphql)
+            .json(&j)
+            .send()
+            .await?
+            .json::<Value>()
+            .await
+            .map_err(LeetcodeError::Reqwest)?;
+        debug!("Response: {}", serde_json::to_string_pretty(&res).unwrap());
+        // cn返回的是数组，com返回的是对象
+        let daily = match &res["data"][field] {
+            Value::Array(records) => records.first().cloned(),
+            Value::Null => None,
+            daily => Some(daily.clone()),
+        };
+        let daily = daily.ok_or_else(|| LeetcodeError::Any(anyhow!("no daily question today")))?;
+        Ok(serde_json::from_value(daily)?)
+    }
+
+    /// 优先使用翻译后的标题
+    pub fn display_title(&self) -> &str {
+        match &self.question.translated_title {
+            Some(title) if !title.is_empty() => title,
+            _ => &self.question.title,
+        }
+    }
+}
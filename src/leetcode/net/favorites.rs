@@ -0,0 +1,188 @@
+//! 收藏夹，`star`/`unstar`操作的是默认收藏夹（第一个收藏夹）
+
+use log::debug;
+use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::{Serialize, Deserialize};
+use serde_json::{json, Value};
+use anyhow::anyhow;
+use crate::leetcode::config::CONST_CONFIG;
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
+
+use crate::leetcode::error::{LeetcodeError, Result};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteList {
+    pub id_hash: String,
+    pub name: String,
+    #[serde(default)]
+    pub is_public_favorite: bool,
+    pub questions: Vec<FavoriteQuestion>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteQuestion {
+    pub question_id: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FavoritesLists {
+    all_favorites: Vec<FavoriteList>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Data {
+    favorites_lists: FavoritesLists,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Response {
+    data: Data,
+}
+
+impl FavoriteList {
+    pub fn contains(&self, question_id: i32) -> bool {
+        let question_id = question_id.to_string();
+        self.questions.iter().any(|question| question.question_id == question_id)
+    }
+
+    pub async fn fetch_all() -> Result<Vec<FavoriteList>> {
+        let j = json!({
+            "query": r#"
+                query favoritesList {
+                  favoritesLists {
+                    allFavorites {
+                      idHash
+                      name
+                      isPublicFavorite
+                      questions {
+                        questionId
+                      }
+                    }
+                  }
+                }
+            "#,
+            "variables": json!({}),
+            "operationName": "favoritesList"
+        });
+        let res = client().await?
+            .post(CONST_CONFIG.url.leetcode.graphql)
+            .json(&j)
+            .send()
+            .await?
+            .json::<Response>()
+            .await
+            .map_err(LeetcodeError::Reqwest)?;
+        debug!("Response: {}", serde_json::to_string_pretty(&res).unwrap());
+        Ok(res.data.favorites_lists.all_favorites)
+    }
+
+    /// 默认收藏夹
+    pub async fn fetch_default() -> Result<FavoriteList> {
+        FavoriteList::fetch_all().await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| LeetcodeError::Any(anyhow!("no favorite list, please login first")))
+    }
+
+    /// 新建收藏夹，接口要求同时加入一道题目，返回新收藏夹的id_hash
+    pub async fn create(name: &str, is_public: bool, question_id: i32) -> Result<String> {
+        let res = mutate("addQuestionToNewFavorite", r#"
+            mutation addQuestionToNewFavorite($name: String!, $isPublicFavorite: Boolean!, $questionId: String!) {
+              addQuestionToNewFavorite(name: $name, isPublicFavorite: $isPublicFavorite, questionId: $questionId) {
+                ok
+                error
+                favoriteIdHash
+              }
+            }
+        "#, json!({
+            "name": name,
+            "isPublicFavorite": is_public,
+            "questionId": question_id.to_string(),
+        })).await?;
+        Ok(res["data"]["addQuestionToNewFavorite"]["favoriteIdHash"].as_str().unwrap_or("").to_string())
+    }
+
+    pub async fn add(&self, question_id: i32) -> Result<()> {
+        mutate("addQuestionToFavorite", QUESTION_MUTATION, json!({
+            "favoriteIdHash": self.id_hash,
+            "questionId": question_id.to_string(),
+        })).await?;
+        Ok(())
+    }
+
+    pub async fn remove(&self, question_id: i32) -> Result<()> {
+        mutate("removeQuestionFromFavorite", QUESTION_MUTATION, json!({
+            "favoriteIdHash": self.id_hash,
+            "questionId": question_id.to_string(),
+        })).await?;
+        Ok(())
+    }
+}
+
+/// 把题目加入或移出默认收藏夹
+pub async fn set_starred(question_id: i32, starred: bool) -> Result<()> {
+    let favorite = FavoriteList::fetch_default().await?;
+    if starred {
+        favorite.add(question_id).await
+    } else {
+        favorite.remove(question_id).await
+    }
+}
+
+async fn client() -> Result<Client> {
+    let cookie = crate::leetcode::cache::get(DB_TREES.auth, DB_KEYS.cookie).await?.unwrap_or("".to_string());
+    let mut headers = HeaderMap::new();
+    // 修改数据的请求需要带上csrftoken
+    if let Some(csrftoken) = cookie.split(';')
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == "csrftoken")
+        .map(|(_, value)| value.to_string()) {
+        headers.insert("x-csrftoken", HeaderValue::from_str(&csrftoken)?);
+    }
+    headers.insert("Cookie", HeaderValue::from_str(&cookie).unwrap());
+    headers.insert("referer", HeaderValue::from_static(CONST_CONFIG.url.leetcode.base));
+    Ok(Client::builder()
+        .default_headers(headers)
+        .build()?)
+}
+
+/// 加入或移出收藏夹，`{op}`替换为操作名
+const QUESTION_MUTATION: &str = r#"
+    mutation {op}($favoriteIdHash: String!, $questionId: String!) {
+      {op}(favoriteIdHash: $favoriteIdHash, questionId: $questionId) {
+        ok
+        error
+      }
+    }
+"#;
+
+/// 执行收藏夹相关的mutation，`ok`为false时返回错误
+async fn mutate(operation: &str, query: &str, variables: Value) -> Result<Value> {
+    let j = json!({
+        "query": query.replace("{op}", operation),
+        "variables": variables,
+        "operationName": operation
+    });
+    let res = client().await?
+        .post(CONST_CONFIG.url.leetcode.graphql)
+        .json(&j)
+        .send()
+        .await?
+        .json::<Value>()
+        .await
+        .map_err(LeetcodeError::Reqwest)?;
+    debug!("Response: {}", serde_json::to_string_pretty(&res).unwrap());
+    let result = &res["data"][operation];
+    if result["ok"].as_bool() != Some(true) {
+        let error = result["error"].as_str()
+            .or_else(|| res["errors"][0]["message"].as_str())
+            .unwrap_or("unknown error");
+        return Err(LeetcodeError::Any(anyhow!("{} failed: {}", operation, error)));
+    }
+    Ok(res)
+}
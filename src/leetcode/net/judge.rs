@@ -3,10 +3,11 @@ use log::debug;
 use reqwest::header::{HeaderMap, HeaderValue};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 use std::time::Duration;
 
 use crate::leetcode::config::CONST_CONFIG;
-use crate::leetcode::cache::DB_KEYS;
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
 use crate::leetcode::error::Result;
 use crate::leetcode::term::icon::Icon;
 
@@ -18,14 +19,14 @@ pub enum StringOrStringVec {
     StringVec(Vec<String>),
 }
 
-impl ToString for StringOrStringVec {
-    fn to_string(&self) -> String {
+impl Display for StringOrStringVec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             StringOrStringVec::String(s) => {
-                s.to_string()
+                write!(f, "{}", s)
             }
             StringOrStringVec::StringVec(v) => {
-                v.join("\n")
+                write!(f, "{}", v.join("\n"))
             }
         }
     }
@@ -94,7 +95,7 @@ impl JudgeResult {
             .veriry
             .replace("$id", submission_id);
         debug!("submission_id: {}, get_url: {}", submission_id, get_url);
-        let cookie = crate::leetcode::cache::get(DB_KEYS.cookie)
+        let cookie = crate::leetcode::cache::get(DB_TREES.auth, DB_KEYS.cookie)
             .await?
             .unwrap_or("".to_string());
 
@@ -132,7 +133,7 @@ impl JudgeResult {
 {}/{} cases passed ({})
 Your runtime beats {}% of {} submissions
 Your memory usage beats {}% of {} submissions ({})"#,
-                    Icon::Yes.to_string(),
+                    Icon::Yes,
                     self.status_msg,
                     self.total_correct.unwrap_or(0),
                     self.total_testcases.unwrap_or(0),
@@ -149,7 +150,7 @@ Your memory usage beats {}% of {} submissions ({})"#,
                 // Runtime error
                 let content = format!(
                     "{} {}\n{}",
-                    Icon::No.to_string(),
+                    Icon::No,
                     self.status_msg,
                     self.full_runtime_error.as_ref().expect("missing full runtime error field"),
                 );
@@ -159,7 +160,7 @@ Your memory usage beats {}% of {} submissions ({})"#,
                 // Compile error
                 let content = format!(
                     "{} {}\n{}",
-                    Icon::No.to_string(),
+                    Icon::No,
                     self.status_msg,
                     self.full_compile_error.as_ref().expect("missing full compile error field")
                 );
@@ -180,13 +181,13 @@ code_output:
 
 expectd_output:
 {}"#,
-                    Icon::No.to_string(),
+                    Icon::No,
                     self.status_msg,
                     self.total_correct.unwrap(),
                     self.total_testcases.unwrap(),
                     self.status_runtime,
                     self.last_testcase.as_ref().expect("missing last testcase field"),
-                    self.code_output.as_ref().expect("missing code output field").to_string(),
+                    self.code_output.as_ref().expect("missing code output field"),
                     self.expected_output.as_ref().expect("missing code output field"),
                 );
                 println!("{}", Red.paint(content));
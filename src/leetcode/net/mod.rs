@@ -1,4 +1,53 @@
+use log::debug;
+use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde_json::Value;
+use anyhow::anyhow;
+use crate::leetcode::config::CONST_CONFIG;
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
+
+use crate::leetcode::error::{LeetcodeError, Result};
+
+pub mod daily;
+pub mod favorites;
+pub mod study_plan;
+pub mod solution;
+pub mod submission;
 pub mod problems_all;
+pub mod problemset;
 pub mod question_data;
+pub mod tag_index;
 pub mod submit;
 pub mod judge;
+pub mod user_status;
+
+/// 执行GraphQL查询，响应中有`errors`时返回第一条错误
+pub(crate) async fn query(j: Value) -> Result<Value> {
+    let cookie = crate::leetcode::cache::get(DB_TREES.auth, DB_KEYS.cookie).await?.unwrap_or("".to_string());
+    let mut headers = HeaderMap::new();
+    headers.insert("Cookie", HeaderValue::from_str(&cookie).unwrap());
+    let client = Client::builder()
+        .default_headers(headers)
+        .build()?;
+    let res = client.post(CONST_CONFIG.url.leetcode.graphql)
+        .json(&j)
+        .send()
+        .await?
+        .json::<Value>()
+        .await
+        .map_err(LeetcodeError::Reqwest)?;
+    debug!("Response: {}", serde_json::to_string_pretty(&res).unwrap());
+    if let Some(message) = res["errors"][0]["message"].as_str() {
+        return Err(LeetcodeError::Any(anyhow!("{}", message)));
+    }
+    Ok(res)
+}
+
+/// 数字和字符串都转换为字符串
+pub(crate) fn as_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
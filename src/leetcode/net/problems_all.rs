@@ -1,13 +1,13 @@
 //! 访问 https://leetcode.cn/api/problems/all/ 返回的结构体
 
+use std::fmt::{Display, Formatter};
 use ansi_term::Color::{Green, Red, Yellow};
 use log::debug;
 use reqwest::Client;
 use reqwest::header::{HeaderMap, HeaderValue};
 use serde::{Serialize, Deserialize};
 use crate::leetcode::config::CONST_CONFIG;
-use crate::leetcode::cache::DB_KEYS;
-use crate::leetcode::term::icon::Icon;
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
 use crate::leetcode::error::{LeetcodeError, Result};
 
 #[derive(Serialize, Deserialize)]
@@ -57,68 +57,70 @@ pub struct Difficulty {
     pub level: i32,
 }
 
-impl ToString for Difficulty {
-    fn to_string(&self) -> String {
+impl Difficulty {
+    pub fn name(&self) -> &'static str {
         match self.level {
-            1 => Green.paint("easy").to_string(),
-            2 => Yellow.paint("medium").to_string(),
-            3 => Red.paint("hard").to_string(),
+            1 => "easy",
+            2 => "medium",
+            3 => "hard",
             _ => panic!("unexpected level")
         }
     }
 }
 
-fn to_width(s: &str, width: usize) -> String {
-    let cur_width = unicode_width::UnicodeWidthStr::width(s);
-    if cur_width > width {
-        let mut s = s[..width-3].to_string();
-        s.push_str("...");
-        return s;
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let color = match self.level {
+            1 => Green,
+            2 => Yellow,
+            _ => Red,
+        };
+        write!(f, "{}", color.paint(self.name()))
     }
-    let mut s = s.to_string();
-    s.push_str(" ".repeat(width - cur_width).as_str());
-    s
 }
 
-impl StatStatus {
-    pub fn pretty_print(&self) {
-        let starred_icon = if self.is_favor {
-            Yellow.paint(Icon::Star.to_string()).to_string()
-        } else {
-            Icon::Empty.to_string()
-        };
-
-        let locked_icon = if self.paid_only {
-            Red.paint(Icon::Lock.to_string()).to_string()
-        } else {
-            Icon::Empty.to_string()
+impl ProblemsAll {
+    /// 根据判题结果更新题目的状态和已解决的数量
+    pub fn update_status(&mut self, question_id: i32, accepted: bool) {
+        let ss = match self.stat_status_pairs.iter_mut().find(|ss| ss.stat.question_id == question_id) {
+            Some(ss) => ss,
+            None => return,
         };
+        if accepted && ss.status.as_deref() != Some("ac") {
+            ss.status = Some("ac".to_string());
+            self.num_solved += 1;
+            match ss.difficulty.level {
+                1 => self.ac_easy += 1,
+                2 => self.ac_medium += 1,
+                3 => self.ac_hard += 1,
+                _ => {}
+            }
+        } else if !accepted && ss.status.is_none() {
+            ss.status = Some("notac".to_string());
+        }
+    }
 
-        let accepted_icon = if self.status.is_some() {
-            Green.paint(Icon::Yes.to_string()).to_string()
-        } else {
-            Icon::Empty.to_string()
-        };
+    pub fn set_favor(&mut self, question_id: i32, is_favor: bool) {
+        if let Some(ss) = self.stat_status_pairs.iter_mut().find(|ss| ss.stat.question_id == question_id) {
+            ss.is_favor = is_favor;
+        }
+    }
 
-        println!(
-            "{} {:2} {} [{:^8}] {} {:6}",
-            starred_icon,
-            locked_icon,
-            accepted_icon,
-            self.stat.question_id,
-            to_width(self.stat.question_title.as_str(), 75),
-            self.difficulty.to_string(),
-        )
+    /// 更新缓存
+    pub async fn store(&self) -> Result<()> {
+        crate::leetcode::cache::set(
+            DB_TREES.problems,
+            DB_KEYS.problems_all.to_string(),
+            serde_json::to_string(self)?)
+            .await
     }
-}
 
-impl ProblemsAll {
     pub async fn fetch() -> Result<ProblemsAll> {
         let mut problems_all: ProblemsAll;
-        if let Some(val) = crate::leetcode::cache::get(DB_KEYS.problems_all).await? {
+        if let Some(val) = crate::leetcode::cache::get(DB_TREES.problems, DB_KEYS.problems_all).await? {
             problems_all = serde_json::from_str(&val)?;
         } else {
-            let cookie = crate::leetcode::cache::get(DB_KEYS.cookie).await?.unwrap_or("".to_string());
+            let cookie = crate::leetcode::cache::get(DB_TREES.auth, DB_KEYS.cookie).await?.unwrap_or("".to_string());
             let mut headers = HeaderMap::new();
             headers.insert("Cookie", HeaderValue::from_str(&cookie).unwrap());
             let client = Client::builder()
@@ -135,6 +137,7 @@ impl ProblemsAll {
                     ss.stat.question_id
                 });
             crate::leetcode::cache::set(
+                DB_TREES.problems,
                 DB_KEYS.problems_all.to_string(),
                 serde_json::to_string(&problems_all).unwrap())
                 .await?;
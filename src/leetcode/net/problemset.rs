@@ -0,0 +1,95 @@
+//! 通过GraphQL按条件查询题目列表
+
+use log::debug;
+use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::{Serialize, Deserialize};
+use serde_json::json;
+use crate::leetcode::config::CONST_CONFIG;
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
+
+use crate::leetcode::error::{LeetcodeError, Result};
+
+/// 每次请求的题目数量
+const PAGE_SIZE: i32 = 100;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemsetQuestion {
+    pub frontend_question_id: String,
+    pub title_slug: String,
+    pub paid_only: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProblemsetQuestionList {
+    has_more: bool,
+    total: i32,
+    questions: Vec<ProblemsetQuestion>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Data {
+    problemset_question_list: ProblemsetQuestionList,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Response {
+    data: Data,
+}
+
+impl ProblemsetQuestion {
+    /// 获取带有某个标签的所有题目，`tag`为标签的slug，例如`dynamic-programming`
+    pub async fn fetch_by_tag(tag: &str) -> Result<Vec<ProblemsetQuestion>> {
+        let cookie = crate::leetcode::cache::get(DB_TREES.auth, DB_KEYS.cookie).await?.unwrap_or("".to_string());
+        let mut headers = HeaderMap::new();
+        headers.insert("Cookie", HeaderValue::from_str(&cookie).unwrap());
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()?;
+        let mut questions = Vec::new();
+        loop {
+            let j = json!({
+                "query": r#"
+                    query problemsetQuestionList($categorySlug: String, $limit: Int, $skip: Int, $filters: QuestionListFilterInput) {
+                      problemsetQuestionList(categorySlug: $categorySlug, limit: $limit, skip: $skip, filters: $filters) {
+                        hasMore
+                        total
+                        questions {
+                          frontendQuestionId
+                          titleSlug
+                          paidOnly
+                        }
+                      }
+                    }
+                "#,
+                "variables": json!({
+                    "categorySlug": "",
+                    "limit": PAGE_SIZE,
+                    "skip": questions.len(),
+                    "filters": json!({
+                        "tags": [tag],
+                    }),
+                }),
+                "operationName": "problemsetQuestionList"
+            });
+            let res = client.post(CONST_CONFIG.url.leetcode.graphql)
+                .json(&j)
+                .send()
+                .await?
+                .json::<Response>()
+                .await
+                .map_err(LeetcodeError::Reqwest)?;
+            let list = res.data.problemset_question_list;
+            debug!("problemset: tag {}, total {}, got {}", tag, list.total, list.questions.len());
+            let empty = list.questions.is_empty();
+            questions.extend(list.questions);
+            if !list.has_more || empty {
+                break;
+            }
+        }
+        Ok(questions)
+    }
+}
@@ -1,10 +1,11 @@
+use std::collections::BTreeMap;
 use log::debug;
 use reqwest::Client;
 use reqwest::header::{HeaderMap, HeaderValue};
 use serde::{Serialize, Deserialize};
 use serde_json::json;
 use crate::leetcode::config::CONST_CONFIG;
-use crate::leetcode::cache::DB_KEYS;
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
 
 use crate::leetcode::error::{LeetcodeError, Result};
 
@@ -29,6 +30,72 @@ pub struct QuestionData {
     pub meta_data: MetaData,
 
     pub translated_content: String,
+
+    // 以下字段在旧版本的缓存中没有
+
+    #[serde(default)]
+    pub topic_tags: Vec<TopicTag>,
+
+    #[serde(default)]
+    pub difficulty: String,
+
+    /// html格式的提示
+    #[serde(default)]
+    pub hints: Vec<String>,
+
+    /// JSON字符串，使用[`QuestionData::similar_questions`]解析
+    #[serde(default)]
+    pub similar_questions: Option<String>,
+
+    /// JSON字符串，只有部分账号能获取到，使用[`QuestionData::company_tags`]解析
+    #[serde(default)]
+    pub company_tag_stats: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarQuestion {
+    pub title: String,
+    pub title_slug: String,
+    pub difficulty: String,
+    pub translated_title: Option<String>,
+}
+
+impl SimilarQuestion {
+    /// 优先使用翻译后的标题
+    pub fn display_title(&self) -> &str {
+        match &self.translated_title {
+            Some(title) if !title.is_empty() => title,
+            _ => &self.title,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyTag {
+    pub name: String,
+    pub slug: String,
+    #[serde(default)]
+    pub times_encountered: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicTag {
+    pub name: String,
+    pub slug: String,
+    pub translated_name: Option<String>,
+}
+
+impl TopicTag {
+    /// 优先使用翻译后的名字
+    pub fn display_name(&self) -> &str {
+        match &self.translated_name {
+            Some(name) if !name.is_empty() => name,
+            _ => &self.name,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -89,8 +156,56 @@ struct Data {
 }
 
 impl QuestionData {
+    pub fn similar_questions(&self) -> Vec<SimilarQuestion> {
+        self.similar_questions.as_deref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default()
+    }
+
+    /// 按出现次数从多到少排序，没有数据时为空
+    pub fn company_tags(&self) -> Vec<CompanyTag> {
+        // 按时间段分组，例如 {"1": [...], "2": [...]}
+        let stats: BTreeMap<String, Vec<CompanyTag>> = self.company_tag_stats.as_deref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default();
+        let mut tags: Vec<CompanyTag> = Vec::new();
+        for tag in stats.into_values().flatten() {
+            match tags.iter_mut().find(|t| t.slug == tag.slug) {
+                Some(t) => t.times_encountered += tag.times_encountered,
+                None => tags.push(tag),
+            }
+        }
+        tags.sort_by_key(|tag| -tag.times_encountered);
+        tags
+    }
+
+    pub async fn is_cached(question_title_slug: &str) -> Result<bool> {
+        let key = crate::leetcode::cache::site_key(question_title_slug);
+        Ok(crate::leetcode::cache::get(DB_TREES.questions, &key).await?.is_some())
+    }
+
+    /// 优先从缓存中读取，没有缓存时才访问网络
     pub async fn fetch(question_title_slug: &str) -> Result<QuestionData> {
-        let cookie = crate::leetcode::cache::get(DB_KEYS.cookie).await?.unwrap_or("".to_string());
+        let key = crate::leetcode::cache::site_key(question_title_slug);
+        if let Some(val) = crate::leetcode::cache::get(DB_TREES.questions, &key).await? {
+            return Ok(serde_json::from_str(&val)?);
+        }
+        QuestionData::refresh(question_title_slug).await
+    }
+
+    /// 从网络获取并更新缓存
+    pub async fn refresh(question_title_slug: &str) -> Result<QuestionData> {
+        let question_data = QuestionData::fetch_remote(question_title_slug).await?;
+        crate::leetcode::cache::set(
+            DB_TREES.questions,
+            crate::leetcode::cache::site_key(question_title_slug),
+            serde_json::to_string(&question_data)?)
+            .await?;
+        Ok(question_data)
+    }
+
+    async fn fetch_remote(question_title_slug: &str) -> Result<QuestionData> {
+        let cookie = crate::leetcode::cache::get(DB_TREES.auth, DB_KEYS.cookie).await?.unwrap_or("".to_string());
         let mut headers = HeaderMap::new();
         headers.insert("Cookie", HeaderValue::from_str(&cookie).unwrap());
         let client = Client::builder()
@@ -109,6 +224,15 @@ impl QuestionData {
                      enableRunCode
                      metaData
                      translatedContent
+                     topicTags {
+                       name
+                       slug
+                       translatedName
+                     }
+                     difficulty
+                     hints
+                     similarQuestions
+                     companyTagStats
                    }
                 }
             "#,
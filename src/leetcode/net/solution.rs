@@ -0,0 +1,121 @@
+//! 题解文章，leetcode.cn使用`questionSolutionArticles`/`solutionArticle`，
+//! leetcode.com使用`questionSolutions`/`topic`
+
+use serde_json::json;
+use crate::leetcode::config::SITE;
+use crate::leetcode::net::{as_string, query};
+
+use crate::leetcode::error::Result;
+
+pub struct SolutionSummary {
+    pub title: String,
+    pub author: String,
+    pub votes: i64,
+    /// cn为文章的slug，com为topic的id
+    pub key: String,
+}
+
+impl SolutionSummary {
+    /// 按热度排列的前`first`篇题解
+    pub async fn fetch_list(question_title_slug: &str, first: usize) -> Result<Vec<SolutionSummary>> {
+        if SITE.ends_with(".cn") {
+            let res = query(json!({
+                "query": r#"
+                    query questionSolutionArticles($questionSlug: String!, $first: Int, $skip: Int, $orderBy: SolutionArticleOrderBy) {
+                      questionSolutionArticles(questionSlug: $questionSlug, first: $first, skip: $skip, orderBy: $orderBy) {
+                        edges {
+                          node {
+                            title
+                            slug
+                            upvoteCount
+                            author {
+                              username
+                            }
+                          }
+                        }
+                      }
+                    }
+                "#,
+                "variables": {"questionSlug": question_title_slug, "first": first, "skip": 0, "orderBy": "DEFAULT"},
+                "operationName": "questionSolutionArticles"
+            })).await?;
+            Ok(res["data"]["questionSolutionArticles"]["edges"].as_array()
+                .map(|edges| edges.iter()
+                    .map(|edge| &edge["node"])
+                    .map(|node| SolutionSummary {
+                        title: as_string(&node["title"]),
+                        author: as_string(&node["author"]["username"]),
+                        votes: node["upvoteCount"].as_i64().unwrap_or(0),
+                        key: as_string(&node["slug"]),
+                    })
+                    .collect())
+                .unwrap_or_default())
+        } else {
+            let res = query(json!({
+                "query": r#"
+                    query communitySolutions($questionSlug: String!, $skip: Int!, $first: Int!, $orderBy: TopicSortingOption) {
+                      questionSolutions(filters: {questionSlug: $questionSlug, skip: $skip, first: $first, orderBy: $orderBy}) {
+                        solutions {
+                          id
+                          title
+                          post {
+                            voteCount
+                            author {
+                              username
+                            }
+                          }
+                        }
+                      }
+                    }
+                "#,
+                "variables": {"questionSlug": question_title_slug, "first": first, "skip": 0, "orderBy": "most_votes"},
+                "operationName": "communitySolutions"
+            })).await?;
+            Ok(res["data"]["questionSolutions"]["solutions"].as_array()
+                .map(|solutions| solutions.iter()
+                    .map(|solution| SolutionSummary {
+                        title: as_string(&solution["title"]),
+                        author: as_string(&solution["post"]["author"]["username"]),
+                        votes: solution["post"]["voteCount"].as_i64().unwrap_or(0),
+                        key: as_string(&solution["id"]),
+                    })
+                    .collect())
+                .unwrap_or_default())
+        }
+    }
+
+    /// 题解的正文，markdown格式
+    pub async fn fetch_content(&self) -> Result<String> {
+        let content = if SITE.ends_with(".cn") {
+            let res = query(json!({
+                "query": r#"
+                    query solutionArticle($slug: String!) {
+                      solutionArticle(slug: $slug) {
+                        content
+                      }
+                    }
+                "#,
+                "variables": {"slug": self.key},
+                "operationName": "solutionArticle"
+            })).await?;
+            as_string(&res["data"]["solutionArticle"]["content"])
+        } else {
+            let res = query(json!({
+                "query": r#"
+                    query communitySolution($topicId: Int!) {
+                      topic(id: $topicId) {
+                        post {
+                          content
+                        }
+                      }
+                    }
+                "#,
+                "variables": {"topicId": self.key.parse::<i64>().unwrap_or(0)},
+                "operationName": "communitySolution"
+            })).await?;
+            // com返回的内容中换行是转义过的
+            as_string(&res["data"]["topic"]["post"]["content"]).replace("\\n", "\n")
+        };
+        Ok(content)
+    }
+}
@@ -0,0 +1,194 @@
+//! 学习计划，例如`leetcode-75`、`top-interview-150`，以及按id_hash指定的题单（收藏夹），
+//! 内容缓存在`plans`命名空间中
+
+use log::debug;
+use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::{Serialize, Deserialize};
+use serde_json::{json, Value};
+use anyhow::anyhow;
+use crate::leetcode::config::{CONST_CONFIG, SITE};
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
+use crate::leetcode::net::favorites::FavoriteList;
+use crate::leetcode::net::query;
+
+use crate::leetcode::error::{LeetcodeError, Result};
+
+/// 获取题单时每次请求的题目数量
+const PAGE_SIZE: usize = 100;
+
+/// 常用的学习计划，`plan list`会同时列出已经缓存的计划
+pub const KNOWN_PLANS: [&str; 6] = [
+    "leetcode-75",
+    "top-interview-150",
+    "top-100-liked",
+    "binary-search",
+    "dynamic-programming",
+    "sql-free-50",
+];
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StudyPlan {
+    pub slug: String,
+    pub name: String,
+    pub plan_sub_groups: Vec<PlanSubGroup>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanSubGroup {
+    pub name: String,
+    pub questions: Vec<PlanQuestion>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanQuestion {
+    pub title: String,
+    pub title_slug: String,
+    pub question_frontend_id: String,
+    pub difficulty: String,
+    #[serde(default)]
+    pub paid_only: bool,
+    pub translated_title: Option<String>,
+}
+
+impl PlanQuestion {
+    /// 优先使用翻译后的标题
+    pub fn display_title(&self) -> &str {
+        match &self.translated_title {
+            Some(title) if !title.is_empty() => title,
+            _ => &self.title,
+        }
+    }
+}
+
+impl StudyPlan {
+    /// 按顺序排列的所有题目
+    pub fn questions(&self) -> impl Iterator<Item = &PlanQuestion> {
+        self.plan_sub_groups.iter().flat_map(|group| group.questions.iter())
+    }
+
+    /// 已经缓存的计划的slug
+    pub async fn cached_slugs() -> Result<Vec<String>> {
+        Ok(crate::leetcode::cache::scan_site(DB_TREES.plans).await?
+            .into_iter()
+            .map(|(slug, _)| slug)
+            .collect())
+    }
+
+    /// 优先从缓存中读取，`refresh`为true时重新获取，`slug`不是学习计划时作为题单的id_hash
+    pub async fn fetch(slug: &str, refresh: bool) -> Result<StudyPlan> {
+        let key = crate::leetcode::cache::site_key(slug);
+        if !refresh {
+            if let Some(val) = crate::leetcode::cache::get(DB_TREES.plans, &key).await? {
+                return Ok(serde_json::from_str(&val)?);
+            }
+        }
+        let plan = StudyPlan::fetch_remote(slug).await?;
+        crate::leetcode::cache::set(DB_TREES.plans, key, serde_json::to_string(&plan)?).await?;
+        Ok(plan)
+    }
+
+    async fn fetch_remote(slug: &str) -> Result<StudyPlan> {
+        let cookie = crate::leetcode::cache::get(DB_TREES.auth, DB_KEYS.cookie).await?.unwrap_or("".to_string());
+        let mut headers = HeaderMap::new();
+        headers.insert("Cookie", HeaderValue::from_str(&cookie).unwrap());
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()?;
+        // leetcode.com没有translatedTitle字段
+        let translated_title = if SITE.ends_with(".cn") { "translatedTitle" } else { "" };
+        let j = json!({
+            "query": format!(r#"
+                query studyPlanDetail($slug: String!) {{
+                  studyPlanV2Detail(planSlug: $slug) {{
+                    slug
+                    name
+                    planSubGroups {{
+                      name
+                      questions {{
+                        title
+                        titleSlug
+                        questionFrontendId
+                        difficulty
+                        paidOnly
+                        {}
+                      }}
+                    }}
+                  }}
+                }}
+            "#, translated_title),
+            "variables": {"slug": slug},
+            "operationName": "studyPlanDetail"
+        });
+        let res = client.post(CONST_CONFIG.url.leetcode.graphql)
+            .json(&j)
+            .send()
+            .await?
+            .json::<Value>()
+            .await
+            .map_err(LeetcodeError::Reqwest)?;
+        debug!("Response: {}", serde_json::to_string_pretty(&res).unwrap());
+        match &res["data"]["studyPlanV2Detail"] {
+            Value::Null => StudyPlan::fetch_problem_list(slug).await,
+            plan => Ok(serde_json::from_value(plan.clone())?),
+        }
+    }
+
+    /// 题单没有分组，所有题目放在一个分组中，名字只有自己的收藏夹才能查到
+    async fn fetch_problem_list(id_hash: &str) -> Result<StudyPlan> {
+        let translated_title = if SITE.ends_with(".cn") { "translatedTitle: titleCn" } else { "" };
+        let mut questions: Vec<PlanQuestion> = Vec::new();
+        loop {
+            let res = query(json!({
+                "query": format!(r#"
+                    query problemsetQuestionList($categorySlug: String, $limit: Int, $skip: Int, $filters: QuestionListFilterInput) {{
+                      problemsetQuestionList(categorySlug: $categorySlug, limit: $limit, skip: $skip, filters: $filters) {{
+                        hasMore
+                        questions {{
+                          title
+                          titleSlug
+                          questionFrontendId: frontendQuestionId
+                          difficulty
+                          paidOnly
+                          {}
+                        }}
+                      }}
+                    }}
+                "#, translated_title),
+                "variables": {
+                    "categorySlug": "",
+                    "limit": PAGE_SIZE,
+                    "skip": questions.len(),
+                    "filters": {"listId": id_hash},
+                },
+                "operationName": "problemsetQuestionList"
+            })).await?;
+            let list = &res["data"]["problemsetQuestionList"];
+            let page: Vec<PlanQuestion> = match &list["questions"] {
+                Value::Null => Vec::new(),
+                page => serde_json::from_value(page.clone())?,
+            };
+            let empty = page.is_empty();
+            questions.extend(page);
+            if empty || list["hasMore"].as_bool() != Some(true) {
+                break;
+            }
+        }
+        if questions.is_empty() {
+            return Err(LeetcodeError::Any(anyhow!("no study plan or problem list named {}", id_hash)));
+        }
+        let name = FavoriteList::fetch_all().await
+            .ok()
+            .and_then(|lists| lists.into_iter().find(|list| list.id_hash == id_hash))
+            .map(|list| list.name)
+            .unwrap_or_else(|| id_hash.to_string());
+        Ok(StudyPlan {
+            slug: id_hash.to_string(),
+            name,
+            plan_sub_groups: vec![PlanSubGroup { name: "problems".to_string(), questions }],
+        })
+    }
+}
@@ -0,0 +1,115 @@
+//! 历史提交，leetcode.cn使用`submissionList`/`submissionDetail`，
+//! leetcode.com使用`questionSubmissionList`/`submissionDetails`
+
+use serde_json::json;
+use anyhow::anyhow;
+use crate::leetcode::config::SITE;
+use crate::leetcode::net::{as_string, query};
+
+use crate::leetcode::error::{LeetcodeError, Result};
+
+pub struct SubmissionDetail {
+    pub id: String,
+    /// 语言的slug，例如`cpp`、`python3`
+    pub lang: String,
+    pub code: String,
+    pub runtime: String,
+    pub memory: String,
+    /// 秒级时间戳
+    pub timestamp: i64,
+}
+
+impl SubmissionDetail {
+    /// 最近一次通过的提交的id，需要登录。提交按时间倒序排列，逐页查找直到找到或没有更多提交
+    pub async fn latest_accepted_id(question_title_slug: &str) -> Result<Option<String>> {
+        const LIMIT: usize = 20;
+        let field = if SITE.ends_with(".cn") { "submissionList" } else { "questionSubmissionList" };
+        let mut offset = 0;
+        loop {
+            let res = query(json!({
+                "query": format!(r#"
+                    query submissionList($offset: Int!, $limit: Int!, $questionSlug: String!) {{
+                      {}(offset: $offset, limit: $limit, questionSlug: $questionSlug) {{
+                        hasNext
+                        submissions {{
+                          id
+                          statusDisplay
+                        }}
+                      }}
+                    }}
+                "#, field),
+                "variables": {"offset": offset, "limit": LIMIT, "questionSlug": question_title_slug},
+                "operationName": "submissionList"
+            })).await?;
+            let list = &res["data"][field];
+            let submissions = list["submissions"].as_array().map(Vec::as_slice).unwrap_or_default();
+            if let Some(submission) = submissions.iter().find(|submission| submission["statusDisplay"] == "Accepted") {
+                return Ok(Some(as_string(&submission["id"])));
+            }
+            if submissions.is_empty() || list["hasNext"].as_bool() != Some(true) {
+                return Ok(None);
+            }
+            offset += submissions.len();
+        }
+    }
+
+    pub async fn fetch(submission_id: &str) -> Result<SubmissionDetail> {
+        let detail = if SITE.ends_with(".cn") {
+            let res = query(json!({
+                "query": r#"
+                    query submissionDetail($submissionId: ID!) {
+                      submissionDetail(submissionId: $submissionId) {
+                        code
+                        runtime
+                        memory
+                        timestamp
+                        lang
+                      }
+                    }
+                "#,
+                "variables": {"submissionId": submission_id},
+                "operationName": "submissionDetail"
+            })).await?;
+            let detail = &res["data"]["submissionDetail"];
+            SubmissionDetail {
+                id: submission_id.to_string(),
+                lang: as_string(&detail["lang"]),
+                code: as_string(&detail["code"]),
+                runtime: as_string(&detail["runtime"]),
+                memory: as_string(&detail["memory"]),
+                timestamp: as_string(&detail["timestamp"]).parse().unwrap_or(0),
+            }
+        } else {
+            let res = query(json!({
+                "query": r#"
+                    query submissionDetails($submissionId: Int!) {
+                      submissionDetails(submissionId: $submissionId) {
+                        code
+                        runtimeDisplay
+                        memoryDisplay
+                        timestamp
+                        lang {
+                          name
+                        }
+                      }
+                    }
+                "#,
+                "variables": {"submissionId": submission_id.parse::<i64>().unwrap_or(0)},
+                "operationName": "submissionDetails"
+            })).await?;
+            let detail = &res["data"]["submissionDetails"];
+            SubmissionDetail {
+                id: submission_id.to_string(),
+                lang: as_string(&detail["lang"]["name"]),
+                code: as_string(&detail["code"]),
+                runtime: as_string(&detail["runtimeDisplay"]),
+                memory: as_string(&detail["memoryDisplay"]),
+                timestamp: as_string(&detail["timestamp"]).parse().unwrap_or(0),
+            }
+        };
+        if detail.code.is_empty() {
+            return Err(LeetcodeError::Any(anyhow!("submission {} not found, please login first", submission_id)));
+        }
+        Ok(detail)
+    }
+}
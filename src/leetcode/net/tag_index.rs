@@ -0,0 +1,57 @@
+//! 标签到题目的索引，缓存在本地，之后按标签过滤时不需要访问网络
+
+use std::collections::{BTreeMap, HashSet};
+use serde::{Serialize, Deserialize};
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
+use crate::leetcode::net::problemset::ProblemsetQuestion;
+
+use crate::leetcode::error::Result;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct TagIndex {
+    /// 标签的slug -> 题目的frontend_question_id
+    pub tags: BTreeMap<String, Vec<String>>,
+}
+
+impl TagIndex {
+    /// 标签名转换为slug，例如`Dynamic Programming`转换为`dynamic-programming`
+    pub fn normalize(tag: &str) -> String {
+        tag.trim()
+            .to_lowercase()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    pub async fn load() -> Result<TagIndex> {
+        match crate::leetcode::cache::get(DB_TREES.problems, DB_KEYS.tag_index).await? {
+            Some(val) => Ok(serde_json::from_str(&val)?),
+            None => Ok(TagIndex::default()),
+        }
+    }
+
+    /// 获取带有`tag`的题目，没有缓存时访问网络并更新缓存
+    pub async fn fetch(tag: &str) -> Result<HashSet<String>> {
+        let tag = TagIndex::normalize(tag);
+        let mut index = TagIndex::load().await?;
+        if !index.tags.contains_key(&tag) {
+            let questions = ProblemsetQuestion::fetch_by_tag(&tag).await?;
+            TagIndex::update(&tag, &questions).await?;
+            index = TagIndex::load().await?;
+        }
+        Ok(index.tags.remove(&tag).unwrap_or_default().into_iter().collect())
+    }
+
+    /// 记录带有`tag`的题目
+    pub async fn update(tag: &str, questions: &[ProblemsetQuestion]) -> Result<()> {
+        let mut index = TagIndex::load().await?;
+        index.tags.insert(
+            TagIndex::normalize(tag),
+            questions.iter().map(|question| question.frontend_question_id.clone()).collect());
+        crate::leetcode::cache::set(
+            DB_TREES.problems,
+            DB_KEYS.tag_index.to_string(),
+            serde_json::to_string(&index)?)
+            .await
+    }
+}
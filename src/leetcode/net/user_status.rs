@@ -0,0 +1,55 @@
+//! 当前登录用户的状态，两个站点都使用`globalData`中的`userStatus`
+
+use log::debug;
+use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::{Serialize, Deserialize};
+use serde_json::{json, Value};
+use crate::leetcode::config::CONST_CONFIG;
+use crate::leetcode::cache::{DB_KEYS, DB_TREES};
+
+use crate::leetcode::error::{LeetcodeError, Result};
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UserStatus {
+    pub is_signed_in: bool,
+    /// 没有登录时为null
+    pub is_premium: Option<bool>,
+}
+
+impl UserStatus {
+    pub async fn fetch() -> Result<UserStatus> {
+        let cookie = crate::leetcode::cache::get(DB_TREES.auth, DB_KEYS.cookie).await?.unwrap_or("".to_string());
+        let mut headers = HeaderMap::new();
+        headers.insert("Cookie", HeaderValue::from_str(&cookie).unwrap());
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()?;
+        let j = json!({
+            "query": r#"
+                query globalData {
+                  userStatus {
+                    isSignedIn
+                    isPremium
+                  }
+                }
+            "#,
+            "variables": json!({}),
+            "operationName": "globalData"
+        });
+        let res = client.post(CONST_CONFIG.url.leetcode.graphql)
+            .json(&j)
+            .send()
+            .await?
+            .json::<Value>()
+            .await
+            .map_err(LeetcodeError::Reqwest)?;
+        debug!("Response: {}", serde_json::to_string_pretty(&res).unwrap());
+        Ok(serde_json::from_value(res["data"]["userStatus"].clone()).unwrap_or_default())
+    }
+
+    pub fn is_premium(&self) -> bool {
+        self.is_signed_in && self.is_premium.unwrap_or(false)
+    }
+}
@@ -0,0 +1,52 @@
+//! 题目的个人笔记，markdown格式，存放在`notes`命名空间中
+
+use std::collections::HashSet;
+use crate::leetcode::cache::DB_TREES;
+use crate::leetcode::error::Result;
+
+pub async fn load(question_title_slug: &str) -> Result<Option<String>> {
+    crate::leetcode::cache::get(DB_TREES.notes, &crate::leetcode::cache::site_key(question_title_slug)).await
+}
+
+/// 内容为空时删除笔记，没有保存过的笔记不需要删除
+pub async fn save(question_title_slug: &str, content: &str) -> Result<()> {
+    let key = crate::leetcode::cache::site_key(question_title_slug);
+    if !content.trim().is_empty() {
+        crate::leetcode::cache::set(DB_TREES.notes, key, content.to_string()).await
+    } else if load(question_title_slug).await?.is_some() {
+        crate::leetcode::cache::remove(DB_TREES.notes, &key).await
+    } else {
+        Ok(())
+    }
+}
+
+/// 当前站点的所有笔记，返回(slug, 内容)
+pub async fn load_all() -> Result<Vec<(String, String)>> {
+    crate::leetcode::cache::scan_site(DB_TREES.notes).await
+}
+
+/// 有笔记的题目的slug
+pub async fn noted_slugs() -> Result<HashSet<String>> {
+    Ok(load_all().await?.into_iter().map(|(slug, _)| slug).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load, save};
+
+    #[tokio::test]
+    async fn empty_new_note_is_not_saved() {
+        save("note-test-empty", "  \n").await.unwrap();
+        assert_eq!(load("note-test-empty").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn clearing_removes_note() {
+        save("note-test-clear", "# two pointers").await.unwrap();
+        assert_eq!(load("note-test-clear").await.unwrap().as_deref(), Some("# two pointers"));
+        save("note-test-clear", "").await.unwrap();
+        assert_eq!(load("note-test-clear").await.unwrap(), None);
+        // 再次清空也不会出错
+        save("note-test-clear", "").await.unwrap();
+    }
+}
@@ -0,0 +1,124 @@
+//! 本地保存的提交记录，存放在`submissions`命名空间中
+
+use chrono::{Duration, Local, NaiveDate, TimeZone};
+use serde::{Serialize, Deserialize};
+use crate::leetcode::cache::DB_TREES;
+use crate::leetcode::error::Result;
+use crate::leetcode::net::judge::JudgeResult;
+
+#[derive(Serialize, Deserialize)]
+pub struct SubmissionRecord {
+    pub submission_id: String,
+    pub question_id: i32,
+    pub question_title_slug: String,
+    pub lang: String,
+    pub status_code: i32,
+    pub status_msg: String,
+    pub status_runtime: String,
+    pub status_memory: String,
+
+    /// 判题完成的时间，毫秒时间戳
+    pub timestamp: u64,
+}
+
+impl SubmissionRecord {
+    pub fn new(question_id: i32, question_title_slug: String, judge_result: &JudgeResult) -> SubmissionRecord {
+        SubmissionRecord {
+            submission_id: judge_result.submission_id.clone(),
+            question_id,
+            question_title_slug,
+            lang: judge_result.lang.clone(),
+            status_code: judge_result.status_code,
+            status_msg: judge_result.status_msg.clone(),
+            status_runtime: judge_result.status_runtime.clone(),
+            status_memory: judge_result.status_memory.clone(),
+            timestamp: judge_result.task_finish_time as u64,
+        }
+    }
+
+    /// 站点之后是slug，方便按题目扫描
+    pub fn key(&self) -> String {
+        crate::leetcode::cache::site_key(&format!("{}/{}", self.question_title_slug, self.submission_id))
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        self.status_code == 10
+    }
+
+    /// 判题完成时的本地日期
+    pub fn date(&self) -> NaiveDate {
+        Local.timestamp_millis_opt(self.timestamp as i64)
+            .single()
+            .map(|time| time.date_naive())
+            .unwrap_or_default()
+    }
+
+    /// 当前站点的所有提交记录
+    pub async fn load_all() -> Result<Vec<SubmissionRecord>> {
+        crate::leetcode::cache::scan_site(DB_TREES.submissions).await?
+            .into_iter()
+            .map(|(_, val)| Ok(serde_json::from_str(&val)?))
+            .collect()
+    }
+}
+
+/// 到`today`为止连续有通过的提交的天数，今天还没有通过时从昨天开始算
+pub fn streak(records: &[SubmissionRecord], today: NaiveDate) -> u32 {
+    let days = records.iter()
+        .filter(|record| record.is_accepted())
+        .map(SubmissionRecord::date)
+        .collect::<std::collections::HashSet<_>>();
+    let mut day = if days.contains(&today) { today } else { today - Duration::days(1) };
+    let mut streak = 0;
+    while days.contains(&day) {
+        streak += 1;
+        day -= Duration::days(1);
+    }
+    streak
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Local, NaiveDate, TimeZone};
+    use super::{streak, SubmissionRecord};
+
+    /// `day`当天中午判题完成的提交
+    fn record(day: NaiveDate, status_code: i32) -> SubmissionRecord {
+        let time = Local.from_local_datetime(&day.and_hms_opt(12, 0, 0).unwrap()).unwrap();
+        SubmissionRecord {
+            submission_id: "1".to_string(),
+            question_id: 1,
+            question_title_slug: "two-sum".to_string(),
+            lang: "rust".to_string(),
+            status_code,
+            status_msg: String::new(),
+            status_runtime: String::new(),
+            status_memory: String::new(),
+            timestamp: time.timestamp_millis() as u64,
+        }
+    }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, d).unwrap()
+    }
+
+    #[test]
+    fn count_consecutive_days_up_to_today() {
+        let records = vec![record(day(8), 10), record(day(9), 10), record(day(10), 10)];
+        assert_eq!(streak(&records, day(10)), 3);
+    }
+
+    #[test]
+    fn today_not_solved_yet_counts_from_yesterday() {
+        let records = vec![record(day(8), 10), record(day(9), 10), record(day(10), 11)];
+        assert_eq!(streak(&records, day(10)), 2);
+    }
+
+    #[test]
+    fn gap_breaks_streak() {
+        let records = vec![record(day(6), 10), record(day(7), 10), record(day(9), 10), record(day(10), 10)];
+        assert_eq!(streak(&records, day(10)), 2);
+        assert_eq!(streak(&records, day(12)), 0);
+        assert_eq!(streak(&[], day(10)), 0);
+    }
+}
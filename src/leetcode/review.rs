@@ -0,0 +1,136 @@
+//! 已通过题目的复习计划，按SM-2算法根据自评分数安排下次复习的日期，存放在`reviews`命名空间中
+
+use chrono::{Duration, NaiveDate};
+use serde::{Serialize, Deserialize};
+use crate::leetcode::cache::DB_TREES;
+use crate::leetcode::error::Result;
+
+/// 自评分数的上限，0表示完全想不起来，5表示轻松完成
+pub const MAX_GRADE: u8 = 5;
+const MIN_EASE: f64 = 1.3;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReviewItem {
+    pub question_id: i32,
+    pub question_title_slug: String,
+    /// 连续记住的次数
+    pub repetitions: u32,
+    /// 距离下次复习的天数
+    pub interval: u32,
+    pub ease: f64,
+    pub due: NaiveDate,
+}
+
+impl ReviewItem {
+    pub fn new(question_id: i32, question_title_slug: String, today: NaiveDate) -> ReviewItem {
+        ReviewItem {
+            question_id,
+            question_title_slug,
+            repetitions: 0,
+            interval: 0,
+            ease: 2.5,
+            due: today,
+        }
+    }
+
+    /// 按SM-2更新复习间隔，分数低于3时从头开始
+    pub fn review(&mut self, grade: u8, today: NaiveDate) {
+        let grade = grade.min(MAX_GRADE);
+        if grade >= 3 {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (self.interval as f64 * self.ease).round() as u32,
+            };
+            self.repetitions += 1;
+        } else {
+            self.repetitions = 0;
+            self.interval = 1;
+        }
+        let q = (MAX_GRADE - grade) as f64;
+        self.ease = (self.ease + 0.1 - q * (0.08 + q * 0.02)).max(MIN_EASE);
+        self.due = today + Duration::days(self.interval as i64);
+    }
+
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        self.due <= today
+    }
+
+    pub async fn load(question_title_slug: &str) -> Result<Option<ReviewItem>> {
+        match crate::leetcode::cache::get(DB_TREES.reviews, &crate::leetcode::cache::site_key(question_title_slug)).await? {
+            Some(val) => Ok(Some(serde_json::from_str(&val)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// 当前站点的所有复习项，按到期日期排序
+    pub async fn load_all() -> Result<Vec<ReviewItem>> {
+        let mut items = crate::leetcode::cache::scan_site(DB_TREES.reviews).await?
+            .into_iter()
+            .map(|(_, val)| Ok(serde_json::from_str(&val)?))
+            .collect::<Result<Vec<ReviewItem>>>()?;
+        items.sort_by_key(|item| item.due);
+        Ok(items)
+    }
+
+    pub async fn store(&self) -> Result<()> {
+        crate::leetcode::cache::set(
+            DB_TREES.reviews,
+            crate::leetcode::cache::site_key(&self.question_title_slug),
+            serde_json::to_string(self)?)
+            .await
+    }
+}
+
+/// 记录一次复习，没有复习项时新建
+pub async fn record(question_id: i32, question_title_slug: &str, grade: u8, today: NaiveDate) -> Result<ReviewItem> {
+    let mut item = ReviewItem::load(question_title_slug).await?
+        .unwrap_or_else(|| ReviewItem::new(question_id, question_title_slug.to_string(), today));
+    item.review(grade, today);
+    item.store().await?;
+    Ok(item)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use super::ReviewItem;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2022, 9, d).unwrap()
+    }
+
+    #[test]
+    fn intervals_grow_when_remembered() {
+        let mut item = ReviewItem::new(1, "two-sum".to_string(), day(1));
+        item.review(5, day(1));
+        assert_eq!((item.repetitions, item.interval, item.due), (1, 1, day(2)));
+        item.review(5, day(2));
+        assert_eq!((item.repetitions, item.interval, item.due), (2, 6, day(8)));
+        // 两次满分后ease为2.7，6 * 2.7 = 16.2
+        item.review(4, day(8));
+        assert_eq!((item.repetitions, item.interval, item.due), (3, 16, day(24)));
+    }
+
+    #[test]
+    fn forgetting_resets() {
+        let mut item = ReviewItem::new(1, "two-sum".to_string(), day(1));
+        item.review(5, day(1));
+        item.review(5, day(2));
+        let ease = item.ease;
+        item.review(1, day(8));
+        assert_eq!((item.repetitions, item.interval, item.due), (0, 1, day(9)));
+        assert!(item.ease < ease);
+    }
+
+    #[test]
+    fn ease_has_lower_bound() {
+        let mut item = ReviewItem::new(1, "two-sum".to_string(), day(1));
+        for _ in 0..10 {
+            item.review(0, day(1));
+        }
+        assert_eq!(item.ease, 1.3);
+        assert!(item.is_due(day(2)));
+        assert!(!item.is_due(day(1)));
+    }
+}
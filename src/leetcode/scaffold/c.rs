@@ -0,0 +1,127 @@
+use serde_json::Value;
+use super::{indent, number, quote, quote_char, Arg, Call, Structures, Template, ValueType};
+
+pub(super) struct C;
+
+impl C {
+    fn type_name(&self, typ: &ValueType) -> Option<&'static str> {
+        let name = match typ {
+            ValueType::Integer => "int",
+            ValueType::Long => "long long",
+            ValueType::Double => "double",
+            ValueType::Boolean => "bool",
+            ValueType::Character => "char",
+            ValueType::String => "char*",
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    fn format(&self, typ: &ValueType) -> Option<&'static str> {
+        let format = match typ {
+            ValueType::Integer => "%d",
+            ValueType::Long => "%lld",
+            ValueType::Double => "%f",
+            ValueType::Boolean => "%s",
+            ValueType::Character => "%c",
+            ValueType::String => "%s",
+            _ => return None,
+        };
+        Some(format)
+    }
+
+    /// 只支持基本类型，数组由`declare`单独处理
+    fn literal(&self, typ: &ValueType, value: &Value) -> Option<String> {
+        match typ {
+            ValueType::Integer | ValueType::Long | ValueType::Double => number(typ, value),
+            ValueType::Boolean => value.as_bool().map(|b| b.to_string()),
+            ValueType::Character => quote_char(value),
+            ValueType::String => quote(value),
+            _ => None,
+        }
+    }
+
+    /// 打印一个值，`size`为数组的长度
+    fn print(&self, typ: &ValueType, name: &str, size: &str) -> Option<Vec<String>> {
+        let bool_value = |name: &str| format!("{} ? \"true\" : \"false\"", name);
+        match typ.elem() {
+            Some(elem) => {
+                let format = self.format(elem)?;
+                let item = format!("{}[i]", name);
+                let item = if *elem == ValueType::Boolean { bool_value(&item) } else { item };
+                Some(vec![
+                    "printf(\"[\");".to_string(),
+                    format!("for (int i = 0; i < {}; i++) printf(i ? \",{}\" : \"{}\", {});", size, format, format, item),
+                    "printf(\"]\\n\");".to_string(),
+                ])
+            }
+            None => {
+                let format = self.format(typ)?;
+                let value = if *typ == ValueType::Boolean { bool_value(name) } else { name.to_string() };
+                Some(vec![format!("printf(\"{}\\n\", {});", format, value)])
+            }
+        }
+    }
+}
+
+impl Template for C {
+    fn prelude(&self, _structures: &Structures) -> String {
+        "#include <stdbool.h>\n#include <stdio.h>\n#include <stdlib.h>\n#include <string.h>\n".to_string()
+    }
+
+    fn declare(&self, arg: &Arg, _call: &Call) -> Option<String> {
+        match &arg.typ {
+            ValueType::Array(elem) | ValueType::List(elem) => {
+                let elem_type = self.type_name(elem)?;
+                let items = arg.value.as_array()?
+                    .iter()
+                    .map(|item| self.literal(elem, item))
+                    .collect::<Option<Vec<_>>>()?;
+                // C不允许长度为0的数组初始化
+                let array = if items.is_empty() {
+                    format!("{} {}[1];", elem_type, arg.name)
+                } else {
+                    format!("{} {}[] = {{{}}};", elem_type, arg.name, items.join(", "))
+                };
+                Some(format!("{} int {}Size = {};", array, arg.name, items.len()))
+            }
+            typ => Some(format!("{} {} = {};",
+                                self.type_name(typ)?,
+                                arg.name,
+                                self.literal(typ, &arg.value)?)),
+        }
+    }
+
+    fn call(&self, call: &Call) -> Option<Vec<String>> {
+        let mut args = call.args.iter()
+            .map(|arg| match arg.typ.elem() {
+                Some(_) => format!("{}, {}Size", arg.name, arg.name),
+                None => arg.name.to_string(),
+            })
+            .collect::<Vec<_>>();
+        let mut statements = Vec::new();
+        match &call.ret {
+            ValueType::Void => {
+                let first = call.args.first()?;
+                statements.push(format!("{}({});", call.name, args.join(", ")));
+                statements.extend(self.print(&first.typ, first.name, &format!("{}Size", first.name))?);
+            }
+            ValueType::Array(elem) | ValueType::List(elem) => {
+                args.push("&returnSize".to_string());
+                statements.push("int returnSize = 0;".to_string());
+                statements.push(format!("{}* result = {}({});", self.type_name(elem)?, call.name, args.join(", ")));
+                statements.extend(self.print(&call.ret, "result", "returnSize")?);
+            }
+            ret => {
+                statements.push(format!("{} result = {}({});", self.type_name(ret)?, call.name, args.join(", ")));
+                statements.extend(self.print(ret, "result", "")?);
+            }
+        }
+        Some(statements)
+    }
+
+    fn wrap_main(&self, mut body: Vec<String>, _structures: &Structures) -> String {
+        body.push("return 0;".to_string());
+        format!("int main() {{\n{}}}\n", indent(body, "    "))
+    }
+}
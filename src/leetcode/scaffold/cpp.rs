@@ -0,0 +1,240 @@
+use serde_json::Value;
+use super::{indent, notation, number, quote, quote_char, Arg, Call, Structures, Template, ValueType};
+
+const LIST_NODE: &str = r#"struct ListNode {
+    int val;
+    ListNode *next;
+    ListNode() : val(0), next(nullptr) {}
+    ListNode(int x) : val(x), next(nullptr) {}
+    ListNode(int x, ListNode *next) : val(x), next(next) {}
+};
+
+ListNode *parseList(const string &s) {
+    ListNode dummy;
+    ListNode *tail = &dummy;
+    for (const string &val : parseValues(s)) {
+        tail = tail->next = new ListNode(stoi(val));
+    }
+    return dummy.next;
+}
+
+ostream &operator<<(ostream &os, ListNode *list) {
+    os << "[";
+    for (ListNode *node = list; node != nullptr; node = node->next) {
+        os << (node == list ? "" : ",") << node->val;
+    }
+    return os << "]";
+}
+"#;
+
+const TREE_NODE: &str = r#"struct TreeNode {
+    int val;
+    TreeNode *left;
+    TreeNode *right;
+    TreeNode() : val(0), left(nullptr), right(nullptr) {}
+    TreeNode(int x) : val(x), left(nullptr), right(nullptr) {}
+    TreeNode(int x, TreeNode *left, TreeNode *right) : val(x), left(left), right(right) {}
+};
+
+TreeNode *parseTree(const string &s) {
+    vector<string> vals = parseValues(s);
+    if (vals.empty() || vals[0] == "null") {
+        return nullptr;
+    }
+    TreeNode *root = new TreeNode(stoi(vals[0]));
+    queue<TreeNode *> q;
+    q.push(root);
+    for (size_t i = 1; i < vals.size(); i++) {
+        TreeNode *node = q.front();
+        TreeNode *child = vals[i] == "null" ? nullptr : new TreeNode(stoi(vals[i]));
+        if (child != nullptr) {
+            q.push(child);
+        }
+        if (i % 2 == 1) {
+            node->left = child;
+        } else {
+            node->right = child;
+            q.pop();
+        }
+    }
+    return root;
+}
+
+ostream &operator<<(ostream &os, TreeNode *root) {
+    vector<string> vals;
+    queue<TreeNode *> q;
+    q.push(root);
+    while (!q.empty()) {
+        TreeNode *node = q.front();
+        q.pop();
+        if (node == nullptr) {
+            vals.push_back("null");
+            continue;
+        }
+        vals.push_back(to_string(node->val));
+        q.push(node->left);
+        q.push(node->right);
+    }
+    while (!vals.empty() && vals.back() == "null") {
+        vals.pop_back();
+    }
+    os << "[";
+    for (size_t i = 0; i < vals.size(); i++) {
+        os << (i == 0 ? "" : ",") << vals[i];
+    }
+    return os << "]";
+}
+"#;
+
+/// 解析`[1,null,2]`
+const PARSE_VALUES: &str = r#"vector<string> parseValues(const string &s) {
+    vector<string> vals;
+    string val;
+    for (char c : s) {
+        if (c == ',' || c == ']') {
+            if (!val.empty()) {
+                vals.push_back(val);
+            }
+            val.clear();
+        } else if (c != '[' && c != ' ') {
+            val += c;
+        }
+    }
+    return vals;
+}
+"#;
+
+pub(super) struct Cpp;
+
+impl Cpp {
+    fn type_name(&self, typ: &ValueType) -> Option<String> {
+        let name = match typ {
+            ValueType::Integer => "int".to_string(),
+            ValueType::Long => "long long".to_string(),
+            ValueType::Double => "double".to_string(),
+            ValueType::Boolean => "bool".to_string(),
+            ValueType::Character => "char".to_string(),
+            ValueType::String => "string".to_string(),
+            ValueType::Array(elem) | ValueType::List(elem) => format!("vector<{}>", self.type_name(elem)?),
+            ValueType::ListNode => "ListNode *".to_string(),
+            ValueType::TreeNode => "TreeNode *".to_string(),
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    fn literal(&self, typ: &ValueType, value: &Value) -> Option<String> {
+        match typ {
+            ValueType::Integer | ValueType::Double => number(typ, value),
+            ValueType::Long => number(typ, value).map(|n| format!("{}LL", n)),
+            ValueType::Boolean => value.as_bool().map(|b| b.to_string()),
+            ValueType::Character => quote_char(value),
+            ValueType::String => quote(value),
+            ValueType::Array(elem) | ValueType::List(elem) => {
+                let items = value.as_array()?
+                    .iter()
+                    .map(|item| self.literal(elem, item))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!("{{{}}}", items.join(", ")))
+            }
+            ValueType::ListNode => notation(value).map(|s| format!("parseList({})", s)),
+            ValueType::TreeNode => notation(value).map(|s| format!("parseTree({})", s)),
+            _ => None,
+        }
+    }
+}
+
+impl Template for Cpp {
+    fn prelude(&self, structures: &Structures) -> String {
+        let mut prelude = vec![r#"#include <algorithm>
+#include <climits>
+#include <functional>
+#include <iostream>
+#include <map>
+#include <numeric>
+#include <queue>
+#include <set>
+#include <stack>
+#include <string>
+#include <unordered_map>
+#include <unordered_set>
+#include <vector>
+
+using namespace std;
+
+template <typename T>
+ostream &operator<<(ostream &os, const vector<T> &v) {
+    os << "[";
+    for (size_t i = 0; i < v.size(); i++) {
+        os << (i == 0 ? "" : ",") << v[i];
+    }
+    return os << "]";
+}
+"#];
+        if !structures.is_empty() {
+            prelude.push(PARSE_VALUES);
+        }
+        if structures.list_node {
+            prelude.push(LIST_NODE);
+        }
+        if structures.tree_node {
+            prelude.push(TREE_NODE);
+        }
+        prelude.join("\n")
+    }
+
+    fn declare(&self, arg: &Arg, _call: &Call) -> Option<String> {
+        let typ = self.type_name(&arg.typ)?;
+        let sep = if typ.ends_with('*') { "" } else { " " };
+        Some(format!("{}{}{} = {};",
+                     typ,
+                     sep,
+                     arg.name,
+                     self.literal(&arg.typ, &arg.value)?))
+    }
+
+    fn call(&self, call: &Call) -> Option<Vec<String>> {
+        let args = call.args.iter()
+            .map(|arg| arg.name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let invoke = format!("Solution().{}({})", call.name, args);
+        if call.ret == ValueType::Void {
+            let first = call.args.first()?.name;
+            Some(vec![format!("{};", invoke), format!("cout << boolalpha << {} << endl;", first)])
+        } else {
+            Some(vec![format!("cout << boolalpha << {} << endl;", invoke)])
+        }
+    }
+
+    fn wrap_main(&self, mut body: Vec<String>, _structures: &Structures) -> String {
+        body.push("return 0;".to_string());
+        format!("int main() {{\n{}}}\n", indent(body, "    "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::leetcode::lang::Language;
+    use crate::leetcode::scaffold::tests::{scaffold, LIST, TREE};
+
+    #[test]
+    fn tree_argument() {
+        let scaffold = scaffold(&Language::CPP, TREE, "[1,null,2,3]", "");
+        assert!(scaffold.prelude.contains("TreeNode *parseTree(const string &s)"));
+        assert!(scaffold.prelude.contains("ostream &operator<<(ostream &os, TreeNode *root)"));
+        assert!(!scaffold.prelude.contains("struct ListNode"));
+        assert!(scaffold.main.contains("TreeNode *root = parseTree(\"[1,null,2,3]\");"));
+        assert!(scaffold.main.contains("cout << boolalpha << Solution().inorderTraversal(root) << endl;"));
+    }
+
+    #[test]
+    fn list_argument_and_return() {
+        let scaffold = scaffold(&Language::CPP, LIST, "[1,2,3]\n2", "");
+        assert!(scaffold.prelude.contains("ListNode *parseList(const string &s)"));
+        assert!(scaffold.prelude.contains("ostream &operator<<(ostream &os, ListNode *list)"));
+        assert!(!scaffold.prelude.contains("struct TreeNode"));
+        assert!(scaffold.main.contains("ListNode *head = parseList(\"[1,2,3]\");\n    int k = 2;\n"));
+        assert!(scaffold.main.contains("cout << boolalpha << Solution().reverseKGroup(head, k) << endl;"));
+    }
+}
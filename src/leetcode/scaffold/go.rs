@@ -0,0 +1,223 @@
+use serde_json::Value;
+use super::{indent, notation, number, quote, quote_char, Arg, Call, Structures, Template, ValueType};
+
+const LIST_NODE: &str = r#"type ListNode struct {
+	Val  int
+	Next *ListNode
+}
+
+func parseList(s string) *ListNode {
+	dummy := &ListNode{}
+	tail := dummy
+	for _, val := range parseValues(s) {
+		tail.Next = &ListNode{Val: *val}
+		tail = tail.Next
+	}
+	return dummy.Next
+}
+
+func serializeList(head *ListNode) string {
+	vals := []string{}
+	for node := head; node != nil; node = node.Next {
+		vals = append(vals, strconv.Itoa(node.Val))
+	}
+	return "[" + strings.Join(vals, ",") + "]"
+}
+"#;
+
+const TREE_NODE: &str = r#"type TreeNode struct {
+	Val   int
+	Left  *TreeNode
+	Right *TreeNode
+}
+
+func parseTree(s string) *TreeNode {
+	vals := parseValues(s)
+	if len(vals) == 0 || vals[0] == nil {
+		return nil
+	}
+	root := &TreeNode{Val: *vals[0]}
+	queue := []*TreeNode{root}
+	for i := 1; i < len(vals); i++ {
+		node := queue[0]
+		var child *TreeNode
+		if vals[i] != nil {
+			child = &TreeNode{Val: *vals[i]}
+			queue = append(queue, child)
+		}
+		if i%2 == 1 {
+			node.Left = child
+		} else {
+			node.Right = child
+			queue = queue[1:]
+		}
+	}
+	return root
+}
+
+func serializeTree(root *TreeNode) string {
+	vals := []string{}
+	queue := []*TreeNode{root}
+	for len(queue) > 0 {
+		node := queue[0]
+		queue = queue[1:]
+		if node == nil {
+			vals = append(vals, "null")
+			continue
+		}
+		vals = append(vals, strconv.Itoa(node.Val))
+		queue = append(queue, node.Left, node.Right)
+	}
+	for len(vals) > 0 && vals[len(vals)-1] == "null" {
+		vals = vals[:len(vals)-1]
+	}
+	return "[" + strings.Join(vals, ",") + "]"
+}
+"#;
+
+/// 解析`[1,null,2]`
+const PARSE_VALUES: &str = r#"func parseValues(s string) []*int {
+	vals := []*int{}
+	for _, val := range strings.Split(strings.Trim(s, "[] "), ",") {
+		val = strings.TrimSpace(val)
+		if val == "" {
+			continue
+		}
+		if val == "null" {
+			vals = append(vals, nil)
+			continue
+		}
+		n, _ := strconv.Atoi(val)
+		vals = append(vals, &n)
+	}
+	return vals
+}
+"#;
+
+pub(super) struct Go;
+
+impl Go {
+    fn type_name(&self, typ: &ValueType) -> Option<String> {
+        let name = match typ {
+            ValueType::Integer => "int".to_string(),
+            ValueType::Long => "int64".to_string(),
+            ValueType::Double => "float64".to_string(),
+            ValueType::Boolean => "bool".to_string(),
+            ValueType::Character => "byte".to_string(),
+            ValueType::String => "string".to_string(),
+            ValueType::Array(elem) | ValueType::List(elem) => format!("[]{}", self.type_name(elem)?),
+            ValueType::ListNode => "*ListNode".to_string(),
+            ValueType::TreeNode => "*TreeNode".to_string(),
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    /// 多维切片只有最外层需要写出类型
+    fn literal(&self, typ: &ValueType, value: &Value, outermost: bool) -> Option<String> {
+        match typ {
+            ValueType::Integer | ValueType::Long | ValueType::Double => number(typ, value),
+            ValueType::Boolean => value.as_bool().map(|b| b.to_string()),
+            ValueType::Character => quote_char(value),
+            ValueType::String => quote(value),
+            ValueType::Array(elem) | ValueType::List(elem) => {
+                let items = value.as_array()?
+                    .iter()
+                    .map(|item| self.literal(elem, item, false))
+                    .collect::<Option<Vec<_>>>()?;
+                let prefix = if outermost { self.type_name(typ)? } else { String::new() };
+                Some(format!("{}{{{}}}", prefix, items.join(", ")))
+            }
+            ValueType::ListNode => notation(value).map(|s| format!("parseList({})", s)),
+            ValueType::TreeNode => notation(value).map(|s| format!("parseTree({})", s)),
+            _ => None,
+        }
+    }
+
+    /// 打印`expr`的语句，链表和二叉树打印为数组表示
+    fn print(&self, typ: &ValueType, expr: &str) -> String {
+        match typ {
+            ValueType::ListNode => format!("fmt.Println(serializeList({}))", expr),
+            ValueType::TreeNode => format!("fmt.Println(serializeTree({}))", expr),
+            _ => format!("fmt.Println({})", expr),
+        }
+    }
+}
+
+impl Template for Go {
+    fn prelude(&self, structures: &Structures) -> String {
+        if structures.is_empty() {
+            return "package main\n\nimport \"fmt\"\n".to_string();
+        }
+        let mut prelude = vec!["package main\n\nimport (\n\t\"fmt\"\n\t\"strconv\"\n\t\"strings\"\n)\n", PARSE_VALUES];
+        if structures.list_node {
+            prelude.push(LIST_NODE);
+        }
+        if structures.tree_node {
+            prelude.push(TREE_NODE);
+        }
+        prelude.join("\n")
+    }
+
+    fn declare(&self, arg: &Arg, _call: &Call) -> Option<String> {
+        let literal = self.literal(&arg.typ, &arg.value, true)?;
+        if arg.typ.elem().is_some() || matches!(arg.typ, ValueType::ListNode | ValueType::TreeNode) {
+            Some(format!("{} := {}", arg.name, literal))
+        } else {
+            Some(format!("{} := {}({})", arg.name, self.type_name(&arg.typ)?, literal))
+        }
+    }
+
+    fn call(&self, call: &Call) -> Option<Vec<String>> {
+        let args = call.args.iter()
+            .map(|arg| arg.name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let invoke = format!("{}({})", call.name, args);
+        if call.ret == ValueType::Void {
+            let first = call.args.first()?;
+            Some(vec![invoke, self.print(&first.typ, first.name)])
+        } else {
+            Some(vec![self.print(&call.ret, &invoke)])
+        }
+    }
+
+    fn wrap_main(&self, mut body: Vec<String>, _structures: &Structures) -> String {
+        // 没有调用题解时fmt不会被用到，而Go不允许未使用的import
+        if !body.iter().any(|line| line.starts_with("fmt.")) {
+            body.push("_ = fmt.Println".to_string());
+        }
+        format!("func main() {{\n{}}}\n", indent(body, "\t"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::leetcode::lang::Language;
+    use crate::leetcode::scaffold::tests::{scaffold, LIST, TREE};
+
+    #[test]
+    fn tree_argument() {
+        let scaffold = scaffold(&Language::GO, TREE, "[1,null,2,3]", "");
+        assert!(scaffold.prelude.starts_with("package main"));
+        assert!(scaffold.main.contains("root := parseTree(\"[1,null,2,3]\")\n\tfmt.Println(inorderTraversal(root))"));
+        assert!(scaffold.prelude.contains("func serializeTree(root *TreeNode) string"));
+        assert!(!scaffold.prelude.contains("ListNode"));
+    }
+
+    #[test]
+    fn list_argument_and_return() {
+        let scaffold = scaffold(&Language::GO, LIST, "[1,2,3]\n2", "");
+        assert!(scaffold.main.contains("head := parseList(\"[1,2,3]\")\n\tk := int(2)\n"));
+        assert!(scaffold.main.contains("fmt.Println(serializeList(reverseKGroup(head, k)))"));
+        assert!(scaffold.prelude.contains("func serializeList(head *ListNode) string"));
+        assert!(!scaffold.prelude.contains("TreeNode"));
+    }
+
+    #[test]
+    fn unmatched_sample_still_uses_fmt() {
+        let scaffold = scaffold(&Language::GO, LIST, "[1,2,3]", "");
+        assert!(scaffold.prelude.contains("\"fmt\""));
+        assert!(scaffold.main.contains("// TODO: call the solution with the input above\n\t_ = fmt.Println\n}"));
+    }
+}
@@ -0,0 +1,269 @@
+use serde_json::Value;
+use super::{indent, notation, number, quote, quote_char, Arg, Call, Structures, Template, ValueType};
+
+const LIST_NODE: &str = r#"class ListNode {
+    int val;
+    ListNode next;
+    ListNode() {}
+    ListNode(int val) { this.val = val; }
+    ListNode(int val, ListNode next) { this.val = val; this.next = next; }
+}
+"#;
+
+const LIST_NODE_HELPERS: &str = r#"static ListNode parseList(String s) {
+    ListNode dummy = new ListNode();
+    ListNode tail = dummy;
+    for (Integer val : parseValues(s)) {
+        tail = tail.next = new ListNode(val);
+    }
+    return dummy.next;
+}
+
+static String serializeList(ListNode head) {
+    StringJoiner joiner = new StringJoiner(",", "[", "]");
+    for (ListNode node = head; node != null; node = node.next) {
+        joiner.add(String.valueOf(node.val));
+    }
+    return joiner.toString();
+}
+"#;
+
+const TREE_NODE: &str = r#"class TreeNode {
+    int val;
+    TreeNode left;
+    TreeNode right;
+    TreeNode() {}
+    TreeNode(int val) { this.val = val; }
+    TreeNode(int val, TreeNode left, TreeNode right) {
+        this.val = val;
+        this.left = left;
+        this.right = right;
+    }
+}
+"#;
+
+const TREE_NODE_HELPERS: &str = r#"static TreeNode parseTree(String s) {
+    List<Integer> vals = parseValues(s);
+    if (vals.isEmpty() || vals.get(0) == null) {
+        return null;
+    }
+    TreeNode root = new TreeNode(vals.get(0));
+    Deque<TreeNode> queue = new ArrayDeque<>();
+    queue.add(root);
+    for (int i = 1; i < vals.size(); i++) {
+        TreeNode node = queue.peek();
+        TreeNode child = vals.get(i) == null ? null : new TreeNode(vals.get(i));
+        if (child != null) {
+            queue.add(child);
+        }
+        if (i % 2 == 1) {
+            node.left = child;
+        } else {
+            node.right = child;
+            queue.poll();
+        }
+    }
+    return root;
+}
+
+static String serializeTree(TreeNode root) {
+    List<String> vals = new ArrayList<>();
+    Queue<TreeNode> queue = new LinkedList<>();
+    queue.add(root);
+    while (!queue.isEmpty()) {
+        TreeNode node = queue.poll();
+        if (node == null) {
+            vals.add("null");
+            continue;
+        }
+        vals.add(String.valueOf(node.val));
+        queue.add(node.left);
+        queue.add(node.right);
+    }
+    while (!vals.isEmpty() && vals.get(vals.size() - 1).equals("null")) {
+        vals.remove(vals.size() - 1);
+    }
+    return "[" + String.join(",", vals) + "]";
+}
+"#;
+
+/// 解析`[1,null,2]`
+const PARSE_VALUES: &str = r#"static List<Integer> parseValues(String s) {
+    List<Integer> vals = new ArrayList<>();
+    for (String val : s.replaceAll("[\\[\\] ]", "").split(",")) {
+        if (!val.isEmpty()) {
+            vals.add(val.equals("null") ? null : Integer.valueOf(val));
+        }
+    }
+    return vals;
+}
+"#;
+
+pub(super) struct Java;
+
+impl Java {
+    fn type_name(&self, typ: &ValueType) -> Option<String> {
+        let name = match typ {
+            ValueType::Integer => "int".to_string(),
+            ValueType::Long => "long".to_string(),
+            ValueType::Double => "double".to_string(),
+            ValueType::Boolean => "boolean".to_string(),
+            ValueType::Character => "char".to_string(),
+            ValueType::String => "String".to_string(),
+            ValueType::Array(elem) => format!("{}[]", self.type_name(elem)?),
+            ValueType::List(elem) => format!("List<{}>", self.boxed_type_name(elem)?),
+            ValueType::ListNode => "ListNode".to_string(),
+            ValueType::TreeNode => "TreeNode".to_string(),
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    /// 泛型参数需要使用包装类型
+    fn boxed_type_name(&self, typ: &ValueType) -> Option<String> {
+        let name = match typ {
+            ValueType::Integer => "Integer".to_string(),
+            ValueType::Long => "Long".to_string(),
+            ValueType::Double => "Double".to_string(),
+            ValueType::Boolean => "Boolean".to_string(),
+            ValueType::Character => "Character".to_string(),
+            _ => self.type_name(typ)?,
+        };
+        Some(name)
+    }
+
+    /// 多维数组只有最外层需要`new T[]...`
+    fn literal(&self, typ: &ValueType, value: &Value, outermost: bool) -> Option<String> {
+        match typ {
+            ValueType::Integer | ValueType::Double => number(typ, value),
+            ValueType::Long => number(typ, value).map(|n| format!("{}L", n)),
+            ValueType::Boolean => value.as_bool().map(|b| b.to_string()),
+            ValueType::Character => quote_char(value),
+            ValueType::String => quote(value),
+            ValueType::Array(elem) => {
+                let items = value.as_array()?
+                    .iter()
+                    .map(|item| self.literal(elem, item, false))
+                    .collect::<Option<Vec<_>>>()?;
+                if outermost {
+                    Some(format!("new {}{{{}}}", self.type_name(typ)?, items.join(", ")))
+                } else {
+                    Some(format!("{{{}}}", items.join(", ")))
+                }
+            }
+            ValueType::List(elem) => {
+                let items = value.as_array()?
+                    .iter()
+                    .map(|item| self.literal(elem, item, true))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!("Arrays.asList({})", items.join(", ")))
+            }
+            ValueType::ListNode => notation(value).map(|s| format!("parseList({})", s)),
+            ValueType::TreeNode => notation(value).map(|s| format!("parseTree({})", s)),
+            _ => None,
+        }
+    }
+
+    /// 打印`expr`的语句，链表和二叉树打印为数组表示
+    fn print(&self, typ: &ValueType, expr: &str) -> String {
+        match typ {
+            ValueType::ListNode => format!("System.out.println(serializeList({}));", expr),
+            ValueType::TreeNode => format!("System.out.println(serializeTree({}));", expr),
+            _ => format!("System.out.println(show({}));", expr),
+        }
+    }
+}
+
+impl Template for Java {
+    fn prelude(&self, _structures: &Structures) -> String {
+        "import java.util.*;\n".to_string()
+    }
+
+    fn declare(&self, arg: &Arg, _call: &Call) -> Option<String> {
+        Some(format!("{} {} = {};",
+                     self.type_name(&arg.typ)?,
+                     arg.name,
+                     self.literal(&arg.typ, &arg.value, true)?))
+    }
+
+    fn call(&self, call: &Call) -> Option<Vec<String>> {
+        let args = call.args.iter()
+            .map(|arg| arg.name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let invoke = format!("new Solution().{}({})", call.name, args);
+        if call.ret == ValueType::Void {
+            let first = call.args.first()?;
+            Some(vec![format!("{};", invoke), self.print(&first.typ, first.name)])
+        } else {
+            Some(vec![self.print(&call.ret, &invoke)])
+        }
+    }
+
+    /// 链表和二叉树的定义放在`Main`之后，辅助函数作为`Main`的静态方法
+    fn wrap_main(&self, body: Vec<String>, structures: &Structures) -> String {
+        let mut helpers = Vec::new();
+        let mut definitions = Vec::new();
+        if !structures.is_empty() {
+            helpers.push(PARSE_VALUES);
+        }
+        if structures.list_node {
+            helpers.push(LIST_NODE_HELPERS);
+            definitions.push(LIST_NODE);
+        }
+        if structures.tree_node {
+            helpers.push(TREE_NODE_HELPERS);
+            definitions.push(TREE_NODE);
+        }
+        let helpers = helpers.iter()
+            .map(|helper| format!("\n{}", indent(helper.lines().map(str::to_string).collect(), "    ")))
+            .collect::<String>();
+        let definitions = definitions.iter()
+            .map(|definition| format!("\n{}", definition))
+            .collect::<String>();
+        format!(r#"// `java <file>` runs the first class in the file
+class Main {{
+    public static void main(String[] args) {{
+{}    }}
+
+    static String show(Object o) {{
+        if (o instanceof int[]) return Arrays.toString((int[]) o);
+        if (o instanceof long[]) return Arrays.toString((long[]) o);
+        if (o instanceof double[]) return Arrays.toString((double[]) o);
+        if (o instanceof boolean[]) return Arrays.toString((boolean[]) o);
+        if (o instanceof char[]) return Arrays.toString((char[]) o);
+        if (o instanceof Object[]) return Arrays.deepToString((Object[]) o);
+        return String.valueOf(o);
+    }}
+{}}}
+{}"#, indent(body, "        "), helpers, definitions)
+    }
+
+    fn main_first(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::leetcode::lang::Language;
+    use crate::leetcode::scaffold::tests::{scaffold, LIST, TREE};
+
+    #[test]
+    fn tree_argument() {
+        let scaffold = scaffold(&Language::JAVA, TREE, "[1,null,2,3]", "");
+        assert!(scaffold.main.contains("TreeNode root = parseTree(\"[1,null,2,3]\");"));
+        assert!(scaffold.main.contains("System.out.println(show(new Solution().inorderTraversal(root)));"));
+        assert!(scaffold.main.contains("static String serializeTree(TreeNode root)"));
+        assert!(!scaffold.main.contains("ListNode"));
+    }
+
+    #[test]
+    fn list_argument_and_return() {
+        let scaffold = scaffold(&Language::JAVA, LIST, "[1,2,3]\n2", "");
+        assert!(scaffold.main.contains("ListNode head = parseList(\"[1,2,3]\");\n        int k = 2;\n"));
+        assert!(scaffold.main.contains("System.out.println(serializeList(new Solution().reverseKGroup(head, k)));"));
+        assert!(scaffold.main.contains("static String serializeList(ListNode head)"));
+        assert!(!scaffold.main.contains("TreeNode"));
+    }
+}
@@ -0,0 +1,369 @@
+//! 把题目的`default_code`包装成可以在本地编译运行的代码
+//!
+//! 生成的文件由三部分组成：语言需要的前置内容（头文件、`struct Solution;`等），
+//! 用标记包围的需要提交的代码，以及根据`sample_test_case`生成的`main`
+
+mod c;
+mod cpp;
+mod go;
+mod java;
+mod python;
+pub mod rust;
+
+use std::fmt::{Display, Formatter};
+use serde_json::Value;
+use crate::leetcode::lang::Language;
+use crate::leetcode::net::question_data::{MetaData, QuestionData};
+
+/// 需要提交的代码的开始标记
+pub const CODE_START: &str = "@lc code=start";
+
+/// 需要提交的代码的结束标记
+pub const CODE_END: &str = "@lc code=end";
+
+/// `MetaData`中参数和返回值的类型
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueType {
+    Integer,
+    Long,
+    Double,
+    Boolean,
+    Character,
+    String,
+    Array(Box<ValueType>),
+    List(Box<ValueType>),
+    ListNode,
+    TreeNode,
+    Void,
+    Other(String),
+}
+
+impl ValueType {
+    pub fn parse(typ: &str) -> ValueType {
+        if let Some(elem) = typ.strip_suffix("[]") {
+            return ValueType::Array(Box::new(ValueType::parse(elem)));
+        }
+        if let Some(elem) = typ.strip_prefix("list<").and_then(|t| t.strip_suffix('>')) {
+            return ValueType::List(Box::new(ValueType::parse(elem)));
+        }
+        match typ {
+            "integer" => ValueType::Integer,
+            "long" => ValueType::Long,
+            "double" => ValueType::Double,
+            "boolean" => ValueType::Boolean,
+            "character" => ValueType::Character,
+            "string" => ValueType::String,
+            "ListNode" => ValueType::ListNode,
+            "TreeNode" => ValueType::TreeNode,
+            "void" => ValueType::Void,
+            _ => ValueType::Other(typ.to_string()),
+        }
+    }
+
+    /// 数组或列表的元素类型
+    pub fn elem(&self) -> Option<&ValueType> {
+        match self {
+            ValueType::Array(elem) | ValueType::List(elem) => Some(elem),
+            _ => None,
+        }
+    }
+}
+
+/// 题目用到的、需要生成定义和辅助函数的数据结构
+#[derive(Default)]
+pub(crate) struct Structures {
+    pub list_node: bool,
+    pub tree_node: bool,
+}
+
+impl Structures {
+    fn new(meta_data: &MetaData) -> Structures {
+        let mut structures = Structures::default();
+        meta_data.params.iter()
+            .map(|param| param.typ.as_str())
+            .chain(std::iter::once(meta_data.ret.typ.as_str()))
+            .for_each(|typ| structures.add(&ValueType::parse(typ)));
+        structures
+    }
+
+    fn add(&mut self, typ: &ValueType) {
+        match typ {
+            ValueType::ListNode => self.list_node = true,
+            ValueType::TreeNode => self.tree_node = true,
+            ValueType::Array(elem) | ValueType::List(elem) => self.add(elem),
+            _ => {}
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.list_node && !self.tree_node
+    }
+}
+
+pub(crate) struct Arg<'a> {
+    pub name: &'a str,
+    pub typ: ValueType,
+    pub value: Value,
+}
+
+/// 用样例输入调用题目函数所需的信息
+pub(crate) struct Call<'a> {
+    pub name: &'a str,
+    pub args: Vec<Arg<'a>>,
+    pub ret: ValueType,
+    /// 题目给出的代码，用来判断参数的传递方式
+    pub code: &'a str,
+}
+
+impl<'a> Call<'a> {
+    /// 样例输入每行对应一个参数，行数不匹配或者无法解析时返回`None`
+    fn new(question_data: &'a QuestionData, code: &'a str) -> Option<Call<'a>> {
+        let meta_data = &question_data.meta_data;
+        let lines = question_data.sample_test_case.lines().collect::<Vec<_>>();
+        if meta_data.manual == Some(true) || lines.len() != meta_data.params.len() {
+            return None;
+        }
+        let args = meta_data.params.iter()
+            .zip(lines)
+            .map(|(param, line)| {
+                Some(Arg {
+                    name: param.name.as_str(),
+                    typ: ValueType::parse(&param.typ),
+                    value: serde_json::from_str(line).ok()?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Call {
+            name: meta_data.name.as_str(),
+            args,
+            ret: ValueType::parse(&meta_data.ret.typ),
+            code,
+        })
+    }
+}
+
+/// 各语言生成代码的方式
+trait Template {
+    /// 头文件以及`structures`的定义和辅助函数
+    fn prelude(&self, structures: &Structures) -> String;
+
+    /// 声明一个参数并用样例的值初始化，不支持的类型返回`None`
+    fn declare(&self, arg: &Arg, call: &Call) -> Option<String>;
+
+    /// 调用函数并打印结果
+    fn call(&self, call: &Call) -> Option<Vec<String>>;
+
+    fn wrap_main(&self, body: Vec<String>, structures: &Structures) -> String;
+
+    /// `main`是否需要放在提交的代码之前
+    fn main_first(&self) -> bool {
+        false
+    }
+}
+
+/// 新增语言时需要在这里加上对应的模板
+fn template(language: &Language) -> Box<dyn Template> {
+    match language.name {
+        "c" => Box::new(c::C),
+        "cpp" => Box::new(cpp::Cpp),
+        "go" => Box::new(go::Go),
+        "java" => Box::new(java::Java),
+        "python" => Box::new(python::Python),
+        "rust" => Box::new(rust::Rust),
+        name => unreachable!("no scaffold template for {}", name),
+    }
+}
+
+pub struct Scaffold {
+    pub prelude: String,
+    /// 需要提交的代码，包含开始和结束标记
+    pub code: String,
+    pub main: String,
+    main_first: bool,
+}
+
+impl Scaffold {
+    pub fn new(language: &Language, question_data: &QuestionData, code: &str) -> Scaffold {
+        let template = template(language);
+        let comment = language.single_line_comment;
+        let structures = Structures::new(&question_data.meta_data);
+
+        let mut body = question_data.sample_test_case.lines()
+            .map(|line| format!("{} {}", comment, line))
+            .collect::<Vec<_>>();
+        let statements = Call::new(question_data, code).and_then(|call| {
+            let mut statements = call.args.iter()
+                .map(|arg| template.declare(arg, &call))
+                .collect::<Option<Vec<_>>>()?;
+            statements.extend(template.call(&call)?);
+            Some(statements)
+        });
+        match statements {
+            Some(statements) => body.extend(statements),
+            None => body.push(format!("{} TODO: call the solution with the input above", comment)),
+        }
+
+        Scaffold {
+            prelude: template.prelude(&structures),
+            code: format!("{} {}\n{}\n{} {}", comment, CODE_START, code.trim_end(), comment, CODE_END),
+            main: template.wrap_main(body, &structures),
+            main_first: template.main_first(),
+        }
+    }
+}
+
+impl Display for Scaffold {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let parts = if self.main_first {
+            [&self.prelude, &self.main, &self.code]
+        } else {
+            [&self.prelude, &self.code, &self.main]
+        };
+        let parts = parts.iter()
+            .map(|part| part.trim_end())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>();
+        writeln!(f, "{}", parts.join("\n\n"))
+    }
+}
+
+/// 取出标记之间需要提交的代码，没有标记时返回`None`
+pub fn extract_code(content: &str) -> Option<String> {
+    let start = content.lines().position(|line| line.trim_end().ends_with(CODE_START))?;
+    let end = content.lines().position(|line| line.trim_end().ends_with(CODE_END))?;
+    if end <= start {
+        return None;
+    }
+    Some(content.lines()
+        .skip(start + 1)
+        .take(end - start - 1)
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// 把标记之间的代码替换为`code`，没有标记时返回`None`
+pub fn replace_code(content: &str, code: &str) -> Option<String> {
+    let lines = content.lines().collect::<Vec<_>>();
+    let start = lines.iter().position(|line| line.trim_end().ends_with(CODE_START))?;
+    let end = lines.iter().position(|line| line.trim_end().ends_with(CODE_END))?;
+    if end <= start {
+        return None;
+    }
+    let mut replaced = lines[..=start].join("\n");
+    replaced.push('\n');
+    if !code.is_empty() {
+        replaced.push_str(code);
+        replaced.push('\n');
+    }
+    replaced.push_str(&lines[end..].join("\n"));
+    replaced.push('\n');
+    Some(replaced)
+}
+
+/// 每行前面加上`prefix`
+fn indent(lines: Vec<String>, prefix: &str) -> String {
+    lines.iter()
+        .map(|line| format!("{}{}\n", prefix, line))
+        .collect()
+}
+
+/// 用JSON的转义方式生成带双引号的字符串，各语言都能识别
+fn quote(value: &Value) -> Option<String> {
+    value.as_str().map(|s| serde_json::to_string(s).unwrap())
+}
+
+/// 字符用单引号包围
+fn quote_char(value: &Value) -> Option<String> {
+    let s = value.as_str()?;
+    let mut chars = s.chars();
+    let c = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+    Some(format!("'{}'", c.escape_default()))
+}
+
+/// 链表和二叉树使用leetcode的数组表示，例如`[1,null,2,3]`，作为字符串传给辅助函数
+fn notation(value: &Value) -> Option<String> {
+    value.as_array()?;
+    Some(serde_json::to_string(&value.to_string()).unwrap())
+}
+
+fn number(typ: &ValueType, value: &Value) -> Option<String> {
+    match typ {
+        ValueType::Double => value.as_f64().map(|n| format!("{:?}", n)),
+        _ => value.as_i64().map(|n| n.to_string()),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use serde_json::json;
+    use crate::leetcode::lang::Language;
+    use crate::leetcode::net::question_data::QuestionData;
+    use super::{extract_code, replace_code, Scaffold};
+
+    /// 用`meta_data`（JSON）和样例输入生成代码
+    pub(crate) fn scaffold(language: &Language, meta_data: &str, sample_test_case: &str, code: &str) -> Scaffold {
+        let question_data: QuestionData = serde_json::from_value(json!({
+            "content": "",
+            "stats": r#"{"totalAccepted": "0", "totalSubmission": "0", "totalAcceptedRaw": 0, "totalSubmissionRaw": 0, "acRate": "0%"}"#,
+            "likes": 0,
+            "dislikes": 0,
+            "codeDefinition": "[]",
+            "sampleTestCase": sample_test_case,
+            "enableRunCode": true,
+            "metaData": meta_data,
+            "translatedContent": "",
+        })).unwrap();
+        Scaffold::new(language, &question_data, code)
+    }
+
+    pub(crate) const TREE: &str = r#"{"name": "inorderTraversal", "params": [{"name": "root", "type": "TreeNode"}], "return": {"type": "list<integer>"}}"#;
+    pub(crate) const LIST: &str = r#"{"name": "reverseKGroup", "params": [{"name": "head", "type": "ListNode"}, {"name": "k", "type": "integer"}], "return": {"type": "ListNode"}}"#;
+
+    #[test]
+    fn code_between_markers() {
+        let scaffold = scaffold(&Language::CPP, LIST, "[1,2,3]\n2", "class Solution {\n};\n");
+        assert_eq!(scaffold.code, "// @lc code=start\nclass Solution {\n};\n// @lc code=end");
+        assert_eq!(extract_code(&scaffold.to_string()).as_deref(), Some("class Solution {\n};"));
+    }
+
+    #[test]
+    fn unmatched_sample_leaves_todo() {
+        let scaffold = scaffold(&Language::PYTHON, LIST, "[1,2,3]", "");
+        assert!(scaffold.main.contains("# [1,2,3]\n    # TODO: call the solution with the input above"));
+    }
+
+    #[test]
+    fn extract_code_between_markers() {
+        let content = "#include <vector>\n// @lc code=start\nint a;\n\nint b;\n// @lc code=end\nint main() {}\n";
+        assert_eq!(extract_code(content).as_deref(), Some("int a;\n\nint b;"));
+        assert_eq!(extract_code("# @lc code=start\n# @lc code=end\n").as_deref(), Some(""));
+    }
+
+    #[test]
+    fn extract_code_without_markers() {
+        assert_eq!(extract_code("int a;\n"), None);
+        assert_eq!(extract_code("// @lc code=start\nint a;\n"), None);
+        assert_eq!(extract_code("// @lc code=end\nint a;\n// @lc code=start\n"), None);
+    }
+
+    #[test]
+    fn replace_code_keeps_the_rest() {
+        let content = "// new description\n// @lc code=start\nclass Solution {};\n// @lc code=end\nint main() {}\n";
+        assert_eq!(replace_code(content, "int a;\nint b;").as_deref(),
+                   Some("// new description\n// @lc code=start\nint a;\nint b;\n// @lc code=end\nint main() {}\n"));
+        assert_eq!(replace_code(content, "").as_deref(),
+                   Some("// new description\n// @lc code=start\n// @lc code=end\nint main() {}\n"));
+        assert_eq!(replace_code("int main() {}\n", "int a;"), None);
+    }
+
+    #[test]
+    fn merge_round_trip() {
+        let old = "// old\n// @lc code=start\nmy solution\n// @lc code=end\n";
+        let new = "// new\n// @lc code=start\ndefault code\n// @lc code=end\nmain\n";
+        let merged = replace_code(new, &extract_code(old).unwrap()).unwrap();
+        assert_eq!(merged, "// new\n// @lc code=start\nmy solution\n// @lc code=end\nmain\n");
+    }
+}
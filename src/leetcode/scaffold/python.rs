@@ -0,0 +1,170 @@
+use serde_json::Value;
+use super::{indent, notation, number, quote, Arg, Call, Structures, Template, ValueType};
+
+const LIST_NODE: &str = r#"class ListNode:
+    def __init__(self, val=0, next=None):
+        self.val = val
+        self.next = next
+
+
+def parse_list(s):
+    dummy = tail = ListNode()
+    for val in parse_values(s):
+        tail.next = ListNode(val)
+        tail = tail.next
+    return dummy.next
+
+
+def serialize_list(head):
+    vals = []
+    while head:
+        vals.append(str(head.val))
+        head = head.next
+    return "[" + ",".join(vals) + "]"
+"#;
+
+const TREE_NODE: &str = r#"class TreeNode:
+    def __init__(self, val=0, left=None, right=None):
+        self.val = val
+        self.left = left
+        self.right = right
+
+
+def parse_tree(s):
+    vals = parse_values(s)
+    if not vals or vals[0] is None:
+        return None
+    root = TreeNode(vals[0])
+    queue = deque([root])
+    for i, val in enumerate(vals[1:]):
+        node = queue[0]
+        child = None if val is None else TreeNode(val)
+        if child:
+            queue.append(child)
+        if i % 2 == 0:
+            node.left = child
+        else:
+            node.right = child
+            queue.popleft()
+    return root
+
+
+def serialize_tree(root):
+    vals = []
+    queue = deque([root])
+    while queue:
+        node = queue.popleft()
+        if node is None:
+            vals.append("null")
+            continue
+        vals.append(str(node.val))
+        queue.append(node.left)
+        queue.append(node.right)
+    while vals and vals[-1] == "null":
+        vals.pop()
+    return "[" + ",".join(vals) + "]"
+"#;
+
+/// 解析`[1,null,2]`
+const PARSE_VALUES: &str = r#"from collections import deque
+from typing import List, Optional
+
+
+def parse_values(s):
+    vals = [val.strip() for val in s.strip("[] ").split(",") if val.strip()]
+    return [None if val == "null" else int(val) for val in vals]
+"#;
+
+pub(super) struct Python;
+
+impl Python {
+    fn literal(&self, typ: &ValueType, value: &Value) -> Option<String> {
+        match typ {
+            ValueType::Integer | ValueType::Long | ValueType::Double => number(typ, value),
+            ValueType::Boolean => value.as_bool().map(|b| if b { "True" } else { "False" }.to_string()),
+            ValueType::Character | ValueType::String => quote(value),
+            ValueType::Array(elem) | ValueType::List(elem) => {
+                let items = value.as_array()?
+                    .iter()
+                    .map(|item| self.literal(elem, item))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!("[{}]", items.join(", ")))
+            }
+            ValueType::ListNode => notation(value).map(|s| format!("parse_list({})", s)),
+            ValueType::TreeNode => notation(value).map(|s| format!("parse_tree({})", s)),
+            _ => None,
+        }
+    }
+
+    /// 打印`expr`的语句，链表和二叉树打印为数组表示
+    fn print(&self, typ: &ValueType, expr: &str) -> String {
+        match typ {
+            ValueType::ListNode => format!("print(serialize_list({}))", expr),
+            ValueType::TreeNode => format!("print(serialize_tree({}))", expr),
+            _ => format!("print({})", expr),
+        }
+    }
+}
+
+impl Template for Python {
+    fn prelude(&self, structures: &Structures) -> String {
+        if structures.is_empty() {
+            return String::new();
+        }
+        let mut prelude = vec![PARSE_VALUES];
+        if structures.list_node {
+            prelude.push(LIST_NODE);
+        }
+        if structures.tree_node {
+            prelude.push(TREE_NODE);
+        }
+        prelude.join("\n\n")
+    }
+
+    fn declare(&self, arg: &Arg, _call: &Call) -> Option<String> {
+        Some(format!("{} = {}", arg.name, self.literal(&arg.typ, &arg.value)?))
+    }
+
+    fn call(&self, call: &Call) -> Option<Vec<String>> {
+        let args = call.args.iter()
+            .map(|arg| arg.name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let invoke = format!("Solution().{}({})", call.name, args);
+        if call.ret == ValueType::Void {
+            let first = call.args.first()?;
+            Some(vec![invoke, self.print(&first.typ, first.name)])
+        } else {
+            Some(vec![self.print(&call.ret, &invoke)])
+        }
+    }
+
+    fn wrap_main(&self, body: Vec<String>, _structures: &Structures) -> String {
+        format!("if __name__ == \"__main__\":\n{}", indent(body, "    "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::leetcode::lang::Language;
+    use crate::leetcode::scaffold::tests::{scaffold, LIST, TREE};
+
+    #[test]
+    fn tree_argument() {
+        let scaffold = scaffold(&Language::PYTHON, TREE, "[1,null,2,3]", "");
+        assert!(scaffold.prelude.contains("def parse_tree(s):"));
+        assert!(scaffold.prelude.contains("def serialize_tree(root):"));
+        assert!(!scaffold.prelude.contains("class ListNode"));
+        assert!(scaffold.main.contains("root = parse_tree(\"[1,null,2,3]\")\n    print(Solution().inorderTraversal(root))"));
+    }
+
+    #[test]
+    fn list_argument_and_return() {
+        let scaffold = scaffold(&Language::PYTHON, LIST, "[1,2,3]\n2", "");
+        assert!(scaffold.prelude.contains("def parse_list(s):"));
+        assert!(scaffold.prelude.contains("def serialize_list(head):"));
+        assert!(!scaffold.prelude.contains("class TreeNode"));
+        assert!(scaffold.main.contains("head = parse_list(\"[1,2,3]\")\n    k = 2\n"));
+        assert!(scaffold.main.contains("print(serialize_list(Solution().reverseKGroup(head, k)))"));
+    }
+}
@@ -0,0 +1,291 @@
+use std::fs;
+use std::path::Path;
+use log::info;
+use serde_json::Value;
+use crate::leetcode::error::Result;
+use super::{indent, notation, number, quote, quote_char, Arg, Call, Structures, Template, ValueType};
+
+const LIST_NODE: &str = r#"#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ListNode {
+    pub val: i32,
+    pub next: Option<Box<ListNode>>,
+}
+
+impl ListNode {
+    #[inline]
+    fn new(val: i32) -> Self {
+        ListNode { next: None, val }
+    }
+}
+
+#[allow(dead_code)]
+fn parse_list(s: &str) -> Option<Box<ListNode>> {
+    let mut head = None;
+    for val in parse_values(s).into_iter().rev().flatten() {
+        let mut node = ListNode::new(val);
+        node.next = head;
+        head = Some(Box::new(node));
+    }
+    head
+}
+
+#[allow(dead_code)]
+fn serialize_list(mut list: &Option<Box<ListNode>>) -> String {
+    let mut vals = Vec::new();
+    while let Some(node) = list {
+        vals.push(node.val.to_string());
+        list = &node.next;
+    }
+    format!("[{}]", vals.join(","))
+}
+"#;
+
+const TREE_NODE: &str = r#"#[derive(Debug, PartialEq, Eq)]
+pub struct TreeNode {
+    pub val: i32,
+    pub left: Option<std::rc::Rc<std::cell::RefCell<TreeNode>>>,
+    pub right: Option<std::rc::Rc<std::cell::RefCell<TreeNode>>>,
+}
+
+impl TreeNode {
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        TreeNode { val, left: None, right: None }
+    }
+}
+
+#[allow(dead_code)]
+fn parse_tree(s: &str) -> Option<std::rc::Rc<std::cell::RefCell<TreeNode>>> {
+    let new_node = |val| std::rc::Rc::new(std::cell::RefCell::new(TreeNode::new(val)));
+    let mut vals = parse_values(s).into_iter();
+    let root = new_node(vals.next().flatten()?);
+    let mut queue = std::collections::VecDeque::from([root.clone()]);
+    while let Some(node) = queue.pop_front() {
+        let node = &mut *node.borrow_mut();
+        for child in [&mut node.left, &mut node.right] {
+            match vals.next() {
+                Some(Some(val)) => {
+                    let child_node = new_node(val);
+                    queue.push_back(child_node.clone());
+                    *child = Some(child_node);
+                }
+                Some(None) => {}
+                None => return Some(root),
+            }
+        }
+    }
+    Some(root)
+}
+
+#[allow(dead_code)]
+fn serialize_tree(root: &Option<std::rc::Rc<std::cell::RefCell<TreeNode>>>) -> String {
+    let mut vals = Vec::new();
+    let mut queue = std::collections::VecDeque::from([root.clone()]);
+    while let Some(node) = queue.pop_front() {
+        match node {
+            Some(node) => {
+                let node = node.borrow();
+                vals.push(node.val.to_string());
+                queue.push_back(node.left.clone());
+                queue.push_back(node.right.clone());
+            }
+            None => vals.push("null".to_string()),
+        }
+    }
+    while vals.last().map(String::as_str) == Some("null") {
+        vals.pop();
+    }
+    format!("[{}]", vals.join(","))
+}
+"#;
+
+/// 解析`[1,null,2]`
+const PARSE_VALUES: &str = r#"fn parse_values(s: &str) -> Vec<Option<i32>> {
+    s.trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(str::trim)
+        .filter(|val| !val.is_empty())
+        .map(|val| val.parse().ok())
+        .collect()
+}
+"#;
+
+/// 由本工具生成的Cargo.toml的标记，只修改带有该标记的文件
+const MANIFEST_MARKER: &str = "# generated by leetcodecli";
+
+pub(super) struct Rust;
+
+impl Rust {
+    fn type_name(&self, typ: &ValueType) -> Option<String> {
+        let name = match typ {
+            ValueType::Integer => "i32".to_string(),
+            ValueType::Long => "i64".to_string(),
+            ValueType::Double => "f64".to_string(),
+            ValueType::Boolean => "bool".to_string(),
+            ValueType::Character => "char".to_string(),
+            ValueType::String => "String".to_string(),
+            ValueType::Array(elem) | ValueType::List(elem) => format!("Vec<{}>", self.type_name(elem)?),
+            ValueType::ListNode => "Option<Box<ListNode>>".to_string(),
+            ValueType::TreeNode => "Option<std::rc::Rc<std::cell::RefCell<TreeNode>>>".to_string(),
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    fn literal(&self, typ: &ValueType, value: &Value) -> Option<String> {
+        match typ {
+            ValueType::Integer | ValueType::Long | ValueType::Double => number(typ, value),
+            ValueType::Boolean => value.as_bool().map(|b| b.to_string()),
+            ValueType::Character => quote_char(value),
+            ValueType::String => quote(value).map(|s| format!("{}.to_string()", s)),
+            ValueType::Array(elem) | ValueType::List(elem) => {
+                let items = value.as_array()?
+                    .iter()
+                    .map(|item| self.literal(elem, item))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!("vec![{}]", items.join(", ")))
+            }
+            ValueType::ListNode => notation(value).map(|s| format!("parse_list({})", s)),
+            ValueType::TreeNode => notation(value).map(|s| format!("parse_tree({})", s)),
+            _ => None,
+        }
+    }
+
+    /// 打印`expr`的语句，链表和二叉树打印为数组表示
+    fn print(&self, typ: &ValueType, expr: &str) -> String {
+        match typ {
+            ValueType::ListNode => format!("println!(\"{{}}\", serialize_list(&{}));", expr),
+            ValueType::TreeNode => format!("println!(\"{{}}\", serialize_tree(&{}));", expr),
+            _ => format!("println!(\"{{:?}}\", {});", expr),
+        }
+    }
+
+    /// 题目给出的签名中该参数是否为`&mut`
+    fn is_mut(&self, arg: &Arg, code: &str) -> bool {
+        code.contains(&format!("{}: &mut", snake_case(arg.name)))
+    }
+}
+
+impl Template for Rust {
+    fn prelude(&self, structures: &Structures) -> String {
+        let mut prelude = vec!["struct Solution;\n"];
+        if structures.list_node {
+            prelude.push(LIST_NODE);
+        }
+        if structures.tree_node {
+            prelude.push(TREE_NODE);
+        }
+        if !structures.is_empty() {
+            prelude.push(PARSE_VALUES);
+        }
+        prelude.join("\n")
+    }
+
+    fn declare(&self, arg: &Arg, call: &Call) -> Option<String> {
+        let binding = if self.is_mut(arg, call.code) { "let mut" } else { "let" };
+        Some(format!("{} {}: {} = {};",
+                     binding,
+                     snake_case(arg.name),
+                     self.type_name(&arg.typ)?,
+                     self.literal(&arg.typ, &arg.value)?))
+    }
+
+    fn call(&self, call: &Call) -> Option<Vec<String>> {
+        let args = call.args.iter()
+            .map(|arg| {
+                if self.is_mut(arg, call.code) {
+                    format!("&mut {}", snake_case(arg.name))
+                } else {
+                    snake_case(arg.name)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let invoke = format!("Solution::{}({})", snake_case(call.name), args);
+        if call.ret == ValueType::Void {
+            let first = call.args.first()?;
+            Some(vec![format!("{};", invoke), self.print(&first.typ, &snake_case(first.name))])
+        } else {
+            Some(vec![self.print(&call.ret, &invoke)])
+        }
+    }
+
+    fn wrap_main(&self, body: Vec<String>, _structures: &Structures) -> String {
+        format!("fn main() {{\n{}}}\n", indent(body, "    "))
+    }
+}
+
+/// leetcode的Rust代码使用snake_case命名
+fn snake_case(name: &str) -> String {
+    let mut s = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            s.push('_');
+            s.push(c.to_ascii_lowercase());
+        } else {
+            s.push(c);
+        }
+    }
+    s
+}
+
+/// 把生成的文件作为一个bin加入`dir`下的Cargo.toml，之后可以`cargo run --bin <name>`
+///
+/// Cargo.toml不存在时创建，已存在但不是本工具生成的则不做修改
+pub fn register_bin(dir: &Path, name: &str, file_name: &str) -> Result<()> {
+    let manifest = dir.join("Cargo.toml");
+    let mut content = if manifest.exists() {
+        fs::read_to_string(&manifest)?
+    } else {
+        format!("{}\n[package]\nname = \"leetcode\"\nversion = \"0.1.0\"\nedition = \"2021\"\n", MANIFEST_MARKER)
+    };
+    if !content.starts_with(MANIFEST_MARKER) {
+        info!("{} is not generated by leetcodecli, skip adding {}", manifest.display(), file_name);
+        return Ok(());
+    }
+    if content.contains(&format!("path = \"{}\"", file_name)) {
+        return Ok(());
+    }
+    content.push_str(&format!("\n[[bin]]\nname = \"{}\"\npath = \"{}\"\n", name, file_name));
+    fs::write(manifest, content)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::leetcode::lang::Language;
+    use crate::leetcode::scaffold::tests::{scaffold, LIST, TREE};
+
+    #[test]
+    fn tree_argument() {
+        let scaffold = scaffold(&Language::RUST, TREE, "[1,null,2,3]", "");
+        assert!(scaffold.prelude.starts_with("struct Solution;"));
+        assert!(scaffold.prelude.contains("fn parse_tree(s: &str)"));
+        assert!(scaffold.prelude.contains("fn parse_values(s: &str)"));
+        assert!(!scaffold.prelude.contains("struct ListNode"));
+        assert_eq!(scaffold.main, "fn main() {\n    // [1,null,2,3]\n    \
+            let root: Option<std::rc::Rc<std::cell::RefCell<TreeNode>>> = parse_tree(\"[1,null,2,3]\");\n    \
+            println!(\"{:?}\", Solution::inorder_traversal(root));\n}\n");
+    }
+
+    #[test]
+    fn list_argument_and_return() {
+        let scaffold = scaffold(&Language::RUST, LIST, "[1,2,3]\n2", "");
+        assert!(scaffold.prelude.contains("fn parse_list(s: &str)"));
+        assert!(scaffold.prelude.contains("fn serialize_list("));
+        assert!(!scaffold.prelude.contains("struct TreeNode"));
+        assert!(scaffold.main.contains("let head: Option<Box<ListNode>> = parse_list(\"[1,2,3]\");\n    let k: i32 = 2;\n"));
+        assert!(scaffold.main.contains("println!(\"{}\", serialize_list(&Solution::reverse_k_group(head, k)));"));
+    }
+
+    #[test]
+    fn mutable_argument() {
+        let meta_data = r#"{"name": "rotate", "params": [{"name": "matrix", "type": "integer[][]"}], "return": {"type": "void"}}"#;
+        let code = "impl Solution {\n    pub fn rotate(matrix: &mut Vec<Vec<i32>>) {\n\n    }\n}";
+        let scaffold = scaffold(&Language::RUST, meta_data, "[[1,2],[3,4]]", code);
+        assert!(scaffold.main.contains("let mut matrix: Vec<Vec<i32>> = vec![vec![1, 2], vec![3, 4]];"));
+        assert!(scaffold.main.contains("Solution::rotate(&mut matrix);\n    println!(\"{:?}\", matrix);"));
+    }
+}
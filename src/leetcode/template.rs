@@ -0,0 +1,176 @@
+//! `pick`生成文件的模板
+//!
+//! 用户可以在 ~/.leetcode/templates/<lang> 中使用handlebars语法自定义生成的文件，
+//! 例如 ~/.leetcode/templates/rust ，没有时使用[`DEFAULT_TEMPLATE`]
+
+use std::fs;
+use std::path::PathBuf;
+use dirs::home_dir;
+use handlebars::{handlebars_helper, no_escape, Context, Handlebars, Helper, HelperDef, HelperResult, Output, RenderContext};
+use serde::Serialize;
+use crate::leetcode::error::Result;
+use crate::leetcode::lang::Language;
+
+/// 注释形式的题目信息和描述，之后是代码
+pub const DEFAULT_TEMPLATE: &str = r#"{{comment}} {{frontend_id}}. {{title}}
+{{comment}} difficulty: {{difficulty}}
+{{#if tags}}{{comment}} tags: {{join tags ", "}}
+{{/if}}{{comment}} {{url}}
+
+{{comment description}}
+
+{{code}}"#;
+
+/// 模板中可以使用的变量
+#[derive(Serialize)]
+pub struct TemplateData<'a> {
+    pub id: i32,
+    pub frontend_id: &'a str,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub difficulty: &'a str,
+    pub tags: Vec<&'a str>,
+    /// 相似题目的标题
+    pub similar_questions: Vec<String>,
+    /// markdown形式的提示
+    pub hints: Vec<String>,
+    pub url: String,
+    pub language: &'a str,
+    /// 单行注释的前缀
+    pub comment: &'a str,
+    /// markdown形式的题目描述，按80列折行
+    pub description: String,
+    /// 完整的代码，包括`prelude`、`solution`和`main`
+    pub code: String,
+    pub prelude: &'a str,
+    /// 需要提交的代码，包含开始和结束标记
+    pub solution: &'a str,
+    pub main: &'a str,
+    pub sample_tests: &'a str,
+}
+
+pub fn template_path(language: &Language) -> Option<PathBuf> {
+    let mut path = home_dir()?;
+    path.push(".leetcode");
+    path.push("templates");
+    path.push(language.name);
+    Some(path)
+}
+
+/// 使用用户的模板渲染，没有时使用默认模板
+pub fn render(language: &Language, data: &TemplateData) -> Result<String> {
+    let template = match template_path(language) {
+        Some(path) if path.is_file() => fs::read_to_string(path)?,
+        _ => DEFAULT_TEMPLATE.to_string(),
+    };
+    render_template(&template, data)
+}
+
+fn render_template(template: &str, data: &TemplateData) -> Result<String> {
+    let mut handlebars = Handlebars::new();
+    // 生成的是代码而不是html
+    handlebars.register_escape_fn(no_escape);
+    handlebars.register_helper("comment", Box::new(CommentHelper { comment: data.comment.to_string() }));
+    handlebars.register_helper("join", Box::new(join));
+    let mut content = handlebars.render_template(template, data)
+        .map_err(anyhow::Error::from)?;
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+    Ok(content)
+}
+
+handlebars_helper!(join: |values: array, sep: str| {
+    values.iter()
+        .map(|value| value.as_str().map(str::to_string).unwrap_or_else(|| value.to_string()))
+        .collect::<Vec<_>>()
+        .join(sep)
+});
+
+/// `{{comment text}}`把每一行变成注释，`{{comment}}`输出注释的前缀
+struct CommentHelper {
+    comment: String,
+}
+
+impl HelperDef for CommentHelper {
+    fn call<'reg: 'rc, 'rc>(&self,
+                            h: &Helper<'reg, 'rc>,
+                            _: &'reg Handlebars<'reg>,
+                            _: &'rc Context,
+                            _: &mut RenderContext<'reg, 'rc>,
+                            out: &mut dyn Output) -> HelperResult {
+        match h.param(0) {
+            Some(param) => {
+                let text = param.value().as_str().unwrap_or_default();
+                out.write(&comment_lines(text, &self.comment))?;
+            }
+            None => out.write(&self.comment)?,
+        }
+        Ok(())
+    }
+}
+
+fn comment_lines(text: &str, comment: &str) -> String {
+    text.lines()
+        .map(|line| format!("{} {}", comment, line).trim_end().to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{comment_lines, render_template, TemplateData, DEFAULT_TEMPLATE};
+
+    fn data() -> TemplateData<'static> {
+        TemplateData {
+            id: 1,
+            frontend_id: "1",
+            slug: "two-sum",
+            title: "Two Sum",
+            difficulty: "easy",
+            tags: vec!["array", "hash-table"],
+            similar_questions: Vec::new(),
+            hints: Vec::new(),
+            url: "https://leetcode.cn/problems/two-sum/".to_string(),
+            language: "cpp",
+            comment: "//",
+            description: "a < b && b > c\n\nreturn indices".to_string(),
+            code: "vector<int> twoSum(vector<int>& nums, int target) {}".to_string(),
+            prelude: "#include <vector>",
+            solution: "",
+            main: "",
+            sample_tests: "[2,7,11,15]\n9",
+        }
+    }
+
+    #[test]
+    fn comment_every_line() {
+        assert_eq!(comment_lines("a\n\n  b", "#"), "# a\n#\n#   b");
+        assert_eq!(comment_lines("", "//"), "");
+    }
+
+    #[test]
+    fn render_without_escaping() {
+        let content = render_template("{{prelude}}\n{{comment description}}", &data()).unwrap();
+        assert_eq!(content, "#include <vector>\n// a < b && b > c\n//\n// return indices\n");
+    }
+
+    #[test]
+    fn comment_prefix_and_join() {
+        let content = render_template("{{comment}} tags: {{join tags \", \"}}\n", &data()).unwrap();
+        assert_eq!(content, "// tags: array, hash-table\n");
+    }
+
+    #[test]
+    fn trailing_newline_added_once() {
+        assert_eq!(render_template("{{slug}}", &data()).unwrap(), "two-sum\n");
+        assert_eq!(render_template("{{slug}}\n", &data()).unwrap(), "two-sum\n");
+    }
+
+    #[test]
+    fn default_template_header() {
+        let content = render_template(DEFAULT_TEMPLATE, &data()).unwrap();
+        assert!(content.starts_with("// 1. Two Sum\n// difficulty: easy\n// tags: array, hash-table\n// https://leetcode.cn/problems/two-sum/\n\n// a < b"));
+        assert!(content.ends_with("int target) {}\n"));
+    }
+}
@@ -1,21 +1,27 @@
+use std::fmt::{Display, Formatter};
+
 pub enum Icon {
     Empty,
     Yes,
     No,
     Star,
+    #[allow(dead_code)]
     Unstar,
     Lock,
+    Note,
 }
 
-impl ToString for Icon {
-    fn to_string(&self) -> String {
-        match self {
-            Icon::Empty => " ".to_string(),
-            Icon::Yes => "✔".to_string(),
-            Icon::No => "✘".to_string(),
-            Icon::Star => "★".to_string(),
-            Icon::Unstar => "☆".to_string(),
-            Icon::Lock => "🔒".to_string(),
-        }
+impl Display for Icon {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let icon = match self {
+            Icon::Empty => " ",
+            Icon::Yes => "✔",
+            Icon::No => "✘",
+            Icon::Star => "★",
+            Icon::Unstar => "☆",
+            Icon::Lock => "🔒",
+            Icon::Note => "📝",
+        };
+        write!(f, "{}", icon)
     }
 }
@@ -1 +1,2 @@
 pub mod icon;
+pub mod text;
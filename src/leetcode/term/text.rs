@@ -0,0 +1,107 @@
+//! 按终端显示宽度截断和对齐字符串，以字素簇为单位处理，不会截断中文、emoji或组合字符
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+const ELLIPSIS: &str = "...";
+
+/// 一个字素簇的显示宽度，ZWJ连接的emoji序列在终端中只占两列
+fn grapheme_width(grapheme: &str) -> usize {
+    grapheme.width().min(2)
+}
+
+/// 字符串在终端中的显示宽度
+pub fn width(s: &str) -> usize {
+    s.graphemes(true).map(grapheme_width).sum()
+}
+
+/// 超过`max_width`时截断并以`...`结尾，结果的宽度不超过`max_width`
+pub fn truncate(s: &str, max_width: usize) -> String {
+    if width(s) <= max_width {
+        return s.to_string();
+    }
+    let (limit, ellipsis) = if max_width > ELLIPSIS.len() {
+        (max_width - ELLIPSIS.len(), ELLIPSIS)
+    } else {
+        (max_width, "")
+    };
+    let mut result = String::new();
+    let mut cur_width = 0;
+    for grapheme in s.graphemes(true) {
+        let w = grapheme_width(grapheme);
+        if cur_width + w > limit {
+            break;
+        }
+        cur_width += w;
+        result.push_str(grapheme);
+    }
+    result.push_str(ellipsis);
+    result
+}
+
+/// 在右侧补空格到`min_width`
+pub fn pad(s: &str, min_width: usize) -> String {
+    let cur_width = width(s);
+    let mut result = s.to_string();
+    if cur_width < min_width {
+        result.push_str(&" ".repeat(min_width - cur_width));
+    }
+    result
+}
+
+/// 截断或补齐到正好`width`
+pub fn fit(s: &str, width: usize) -> String {
+    pad(&truncate(s, width), width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fit, pad, truncate, width};
+
+    #[test]
+    fn ascii() {
+        assert_eq!(fit("Two Sum", 10), "Two Sum   ");
+        assert_eq!(fit("Longest Palindromic Substring", 10), "Longest...");
+        assert_eq!(truncate("Two Sum", 7), "Two Sum");
+        assert_eq!(truncate("Two Sum", 2), "Tw");
+    }
+
+    #[test]
+    fn chinese() {
+        let title = "最长回文子串";
+        assert_eq!(width(title), 12);
+        assert_eq!(fit(title, 12), title);
+        assert_eq!(fit(title, 14), "最长回文子串  ");
+        // 剩下的7列只能放下3个汉字，多出的一列用空格补齐
+        assert_eq!(fit(title, 10), "最长回... ");
+        assert_eq!(width(&fit(title, 10)), 10);
+        assert_eq!(fit("两数之和", 1), " ");
+    }
+
+    #[test]
+    fn emoji() {
+        assert_eq!(width("🔒"), 2);
+        // 家庭emoji由ZWJ连接，是一个字素簇
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(width(family), 2);
+        assert_eq!(fit(&format!("{}{}", family, "abcdef"), 6), "👨\u{200d}👩\u{200d}👧a...");
+        assert_eq!(width(&fit("🎉🎉🎉🎉", 6)), 6);
+    }
+
+    #[test]
+    fn full_width_punctuation() {
+        let title = "剑指 Offer 03. 数组中重复的数字（简单）：";
+        for w in 0..=width(title) + 2 {
+            assert_eq!(width(&fit(title, w)), w);
+        }
+        assert_eq!(fit("（）：，", 7), "（）...");
+    }
+
+    #[test]
+    fn combining_characters() {
+        let s = "e\u{301}e\u{301}e\u{301}e\u{301}e\u{301}";
+        assert_eq!(width(s), 5);
+        assert_eq!(truncate(s, 4), "e\u{301}...");
+        assert_eq!(pad("e\u{301}", 3), "e\u{301}  ");
+    }
+}